@@ -4,20 +4,17 @@ use std::str::FromStr;
 
 use util::Input;
 
-fn main() -> Result<()> {
-    let input = Input::load("day01/input")?;
-
-    println!("Part 1:");
-    println!("{}", part1(&input)?);
-
-    println!("Part 2:");
-    println!("{}", part2(&input)?);
-    Ok(())
+util::register! {
+    year: 2022,
+    day: 1,
+    title: "Calorie Counting",
+    part1: part1,
+    part2: part2,
 }
 
 /// Get all the elves and their calorie counts
-fn get_elves(input: &Input) -> Result<Vec<u32>> {
-    Ok(input
+pub fn get_elves(input: &Input) -> Result<Vec<u32>> {
+    input
         .as_lines()
         .batching(|it| {
             // Consume the lines in batches, making a new batch on each empty line
@@ -30,22 +27,30 @@ fn get_elves(input: &Input) -> Result<Vec<u32>> {
                 // Match an empty vector, indicating the end of the input
                 Ok(empty) if empty.is_empty() => None,
                 // Sum all calorie values into a total and yield that
-                Ok(elf) => Some(Ok(elf.into_iter().sum())),
+                Ok(elf) => Some(total_calories(elf)),
                 // Forward errors
-                Err(error) => Some(Err(error)),
+                Err(error) => Some(Err(error.into())),
             }
         })
-        .try_collect::<_, Vec<_>, _>()?)
+        .try_collect::<_, Vec<_>, _>()
+}
+
+/// Sum up calories, failing instead of overflowing
+fn total_calories(calories: impl IntoIterator<Item = u32>) -> Result<u32> {
+    calories
+        .into_iter()
+        .try_fold(0u32, |total, calories| total.checked_add(calories))
+        .ok_or_else(|| anyhow!("Calorie count overflow"))
 }
 
-fn part1(input: &Input) -> Result<u32> {
+pub fn part1(input: &Input) -> Result<u32> {
     get_elves(input)?
         .into_iter()
         .max()
         .ok_or_else(|| anyhow!("No calories in input"))
 }
-fn part2(input: &Input) -> Result<u32> {
-    Ok(get_elves(input)?.into_iter().sorted().rev().take(3).sum())
+pub fn part2(input: &Input) -> Result<u32> {
+    total_calories(get_elves(input)?.into_iter().sorted().rev().take(3))
 }
 
 #[cfg(test)]
@@ -73,4 +78,10 @@ mod test {
         assert_eq!(part2(&input).unwrap(), 45000);
         Ok(())
     }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
 }
@@ -5,11 +5,19 @@ use std::collections::HashMap;
 
 use util::Input;
 
+util::register! {
+    year: 2022,
+    day: 2,
+    title: "Rock Paper Scissors",
+    part1: part1,
+    part2: part2,
+}
+
 static SCORE_TABLE: OnceCell<HashMap<&'static str, u32>> = OnceCell::new();
 
 /// Score table for part 1
 #[allow(clippy::identity_op)]
-fn get_score_table() -> &'static HashMap<&'static str, u32> {
+pub fn get_score_table() -> &'static HashMap<&'static str, u32> {
     SCORE_TABLE.get_or_init(|| {
         vec![
             ("A X", 1 + 3), // Rock vs rock
@@ -31,7 +39,7 @@ static SCORE_TABLE2: OnceCell<HashMap<&'static str, u32>> = OnceCell::new();
 
 /// Score table for part 2
 #[allow(clippy::identity_op)]
-fn get_score_table2() -> &'static HashMap<&'static str, u32> {
+pub fn get_score_table2() -> &'static HashMap<&'static str, u32> {
     SCORE_TABLE2.get_or_init(|| {
         vec![
             ("A X", 3 + 0), // Loss vs rock means scissors
@@ -49,19 +57,8 @@ fn get_score_table2() -> &'static HashMap<&'static str, u32> {
     })
 }
 
-fn main() -> Result<()> {
-    let input = Input::load("day02/input")?;
-
-    println!("Part 1:");
-    println!("{}", part1(&input)?);
-
-    println!("Part 2:");
-    println!("{}", part2(&input)?);
-    Ok(())
-}
-
 /// Calculate the total score based on a score table
-fn calculate_score(input: &Input, score_table: &HashMap<&str, u32>) -> Result<u32> {
+pub fn calculate_score(input: &Input, score_table: &HashMap<&str, u32>) -> Result<u32> {
     // Iterate over all lines, fetching the score from the score table and the summing it up
     process_results(
         input.trim_trailing_newlines().as_lines().map(|line| {
@@ -73,11 +70,11 @@ fn calculate_score(input: &Input, score_table: &HashMap<&str, u32>) -> Result<u3
     )
 }
 
-fn part1(input: &Input) -> Result<u32> {
+pub fn part1(input: &Input) -> Result<u32> {
     calculate_score(input, get_score_table())
 }
 
-fn part2(input: &Input) -> Result<u32> {
+pub fn part2(input: &Input) -> Result<u32> {
     calculate_score(input, get_score_table2())
 }
 
@@ -100,4 +97,10 @@ mod test {
         assert_eq!(part2(&input).unwrap(), 12);
         Ok(())
     }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
 }
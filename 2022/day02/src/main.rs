@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    util::run(
+        "2022/day02/input",
+        aoc2022_day02::part1,
+        aoc2022_day02::part2,
+    )
+}
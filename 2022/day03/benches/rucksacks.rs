@@ -0,0 +1,58 @@
+//! Compare finding the shared items of the rucksacks with bit sets, as the solution does, with the
+//! hash sets it used before. Run with `cargo bench -p aoc2022-day03`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::HashSet;
+
+use util::Input;
+
+const RUCKSACKS: [&str; 6] = [
+    "vJrwpWtwJgWrhcsFMMfFFhFp",
+    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+    "PmmdzqPrVvPwwTWBwg",
+    "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+    "ttgJtRGJQctTZtZT",
+    "CrZsJsPPZsGzwwsLwLmpwMDw",
+];
+
+/// Get the priority of an item, like the solution did before it used bit sets
+fn priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        'A'..='Z' => item as u32 - 'A' as u32 + 27,
+        _ => 0,
+    }
+}
+
+/// Sum the priorities of the items in both compartments using hash sets
+fn part1_hash_sets(input: &Input) -> u32 {
+    input
+        .trim_trailing_newlines()
+        .as_lines()
+        .flat_map(|line| {
+            let (first, second) = line.split_at(line.len() / 2);
+            let first: HashSet<char> = first.chars().collect();
+            let second: HashSet<char> = second.chars().collect();
+            first
+                .intersection(&second)
+                .map(|&item| priority(item))
+                .collect::<Vec<_>>()
+        })
+        .sum()
+}
+
+fn shared_items(c: &mut Criterion) {
+    let input = Input::from_lines(RUCKSACKS.repeat(100));
+    assert_eq!(
+        part1_hash_sets(&input),
+        aoc2022_day03::part1(&input).unwrap()
+    );
+    let mut group = c.benchmark_group("shared items");
+    group.bench_function("HashSet", |b| b.iter(|| part1_hash_sets(black_box(&input))));
+    group.bench_function("BitSet", |b| {
+        b.iter(|| aoc2022_day03::part1(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, shared_items);
+criterion_main!(benches);
@@ -0,0 +1,115 @@
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+
+use util::bitset::{Alphabet, BitSet};
+use util::Input;
+
+util::register! {
+    year: 2022,
+    day: 3,
+    title: "Rucksack Reorganization",
+    part1: part1,
+    part2: part2,
+}
+
+/// The gear in (part of) an elfs backpack, numbered so a's are 0 up to Z's which are 51
+pub type Gear = BitSet<u64>;
+
+/// Get the gear in (part of) a backpack
+pub fn gear(items: &str) -> Result<Gear> {
+    Gear::from_chars(items, &Alphabet::LETTERS)
+}
+
+/// Get the priority of a piece of gear
+pub fn priority(item: usize) -> u32 {
+    item as u32 + 1
+}
+
+pub fn part1(input: &Input) -> Result<u32> {
+    input
+        .trim_trailing_newlines()
+        .as_lines()
+        .map(|line| {
+            // Split the line in the middle to get the two compartments, which can't be done in
+            // the middle of a character
+            let (first, second) = line
+                .split_at_checked(line.len() / 2)
+                .ok_or_else(|| anyhow!("Invalid rucksack {}", line))?;
+            // Find the duplicates by using set intersection
+            let duplicates = gear(first)? & gear(second)?;
+            Ok(duplicates.iter().map(priority).sum::<u32>())
+        })
+        .sum()
+}
+
+pub fn part2(input: &Input) -> Result<u32> {
+    input
+        .trim_trailing_newlines()
+        .as_lines()
+        .chunks(3)
+        .into_iter()
+        .map(|group| {
+            // Find the element in all three backpacks of a group of elves using set intersection
+            let (first, second, third) = group
+                .map(gear)
+                .collect_tuple()
+                .ok_or_else(|| anyhow!("Number of elves not divisible by 3"))?;
+            let badge = first? & second? & third?;
+            if badge.len() != 1 {
+                bail!("Invalid group with {} shared items", badge.len())
+            }
+            Ok(badge.iter().map(priority).sum::<u32>())
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{part1, part2};
+    use anyhow::Result;
+    use util::Input;
+
+    #[test]
+    pub fn test_part1() -> Result<()> {
+        let input = Input::from_lines([
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ]);
+        assert_eq!(part1(&input).unwrap(), 157);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_part2() -> Result<()> {
+        let input = Input::from_lines([
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ]);
+        assert_eq!(part2(&input).unwrap(), 70);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_non_ascii() {
+        // expect rucksacks with items that aren't letters to be rejected, wherever they are
+        for line in ["é", "é1", "aéb", "😀\n 1 \n\n", "ab1é"] {
+            let input = Input::from(line.to_string());
+            assert!(part1(&input).is_err(), "{:?}", line);
+            assert!(part2(&input).is_err(), "{:?}", line);
+        }
+    }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
+}
@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    util::run(
+        "2022/day03/input",
+        aoc2022_day03::part1,
+        aoc2022_day03::part2,
+    )
+}
@@ -0,0 +1,124 @@
+use anyhow::{anyhow, Result};
+use itertools::{process_results, Itertools};
+
+use util::interval::Interval;
+use util::parse::{number, parse_all};
+use util::Input;
+
+util::register! {
+    year: 2022,
+    day: 4,
+    title: "Camp Cleanup",
+    part1: part1,
+    part2: part2,
+}
+
+pub struct Pair {
+    pub left: Sections,
+    pub right: Sections,
+}
+
+/// A range of sections assigned to an elf
+pub struct Sections(pub Interval<u32>);
+
+/// A pair of elves
+impl Pair {
+    /// Create a new pair of elves
+    pub fn new(line: &str) -> Result<Pair> {
+        let (left, right) = process_results(line.splitn(2, ',').map(Sections::new), |ranges| {
+            ranges
+                .collect_tuple()
+                .ok_or_else(|| anyhow!("Invalid line {}", line))
+        })??;
+        Ok(Pair { left, right })
+    }
+
+    pub fn overlaps_fully(&self) -> bool {
+        self.left.0.contains_interval(&self.right.0) || self.right.0.contains_interval(&self.left.0)
+    }
+
+    pub fn overlaps_partially(&self) -> bool {
+        self.left.0.intersects(&self.right.0)
+    }
+}
+
+impl Sections {
+    pub fn new(range: &str) -> Result<Sections> {
+        let (start, end) = range
+            .split_once('-')
+            .ok_or_else(|| anyhow!("Invalid range {}", range))?;
+        let section = number::<u32>();
+        let (start, end) = (parse_all(&section, start)?, parse_all(&section, end)?);
+        Interval::new(start, end)
+            .map(Sections)
+            .ok_or_else(|| anyhow!("Invalid range {}", range))
+    }
+}
+
+pub fn count_overlaps(input: &Input, overlapping_predicate: impl Fn(&Pair) -> bool) -> Result<u32> {
+    input
+        .trim_trailing_newlines()
+        .as_lines()
+        .try_fold(0u32, |total, line| {
+            Pair::new(line)
+                .map(|pair| overlapping_predicate(&pair))
+                .map(|overlaps| if overlaps { total + 1 } else { total })
+        })
+}
+
+pub fn part1(input: &Input) -> Result<u32> {
+    count_overlaps(input, Pair::overlaps_fully)
+}
+
+pub fn part2(input: &Input) -> Result<u32> {
+    count_overlaps(input, Pair::overlaps_partially)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{part1, part2};
+    use anyhow::Result;
+    use util::Input;
+
+    #[test]
+    pub fn test_part1() -> Result<()> {
+        let input = Input::from_lines([
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ]);
+        assert_eq!(part1(&input).unwrap(), 2);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_part2() -> Result<()> {
+        let input = Input::from_lines([
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ]);
+        assert_eq!(part2(&input).unwrap(), 4);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_malformed() {
+        // expect ranges that aren't two sections separated by a dash to be rejected
+        for line in [
+            "2 4,6-8",
+            "a2b4,6-8",
+            "2--4,6-8",
+            "+2-4,6-8",
+            "2-4-6,6-8",
+            "4-2,6-8",
+            "2-4",
+        ] {
+            let input = Input::from_lines([line]);
+            assert!(part1(&input).is_err(), "{}", line);
+            assert!(part2(&input).is_err(), "{}", line);
+        }
+    }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+use util::columns::Drawing;
+use util::ints::ints_array;
+use util::{trace, Input};
+
+util::register! {
+    year: 2022,
+    day: 5,
+    title: "Supply Stacks",
+    part1: part1,
+    part2: part2,
+}
+
+/// Stacks of crates, with the top crate first in every stack
+pub struct Stacks {
+    pub stacks: VecDeque<VecDeque<char>>,
+}
+
+impl Stacks {
+    /// Parse stacks from a drawing of them
+    pub fn new(layout: &str) -> Result<Stacks> {
+        let stacks = Drawing::parse(layout)?
+            .columns()
+            .into_iter()
+            .map(VecDeque::from)
+            .collect();
+        Ok(Stacks { stacks })
+    }
+}
+
+/// Operate the crane according to the moves in the input and return the crates on top of each stack
+///
+/// `move_crates` is passed zero-based stack indexes and never asked to move more crates than there
+/// are on the stack they are moved from
+pub fn operate_crane(
+    input: &Input,
+    move_crates: fn(count: usize, from: usize, to: usize, stacks: &mut Stacks),
+) -> Result<String> {
+    let (stacks, moves) = input
+        .as_str()
+        .splitn(2, "\n\n")
+        .collect_tuple()
+        .ok_or_else(|| anyhow!("Could not split into stacks and moves"))?;
+    let mut stacks = Stacks::new(stacks)?;
+    for m in moves.split('\n').filter(|m| !m.is_empty()) {
+        let [count, from, to] = ints_array::<usize, 3>(m)?;
+        let num_stacks = stacks.stacks.len();
+        if !(1..=num_stacks).contains(&from) || !(1..=num_stacks).contains(&to) {
+            bail!("Invalid move {} with {} stacks", m, num_stacks);
+        }
+        trace::debug!(count, from, to, "Moving crates");
+        // Moving crates onto the stack they came from doesn't change anything
+        if from != to {
+            let count = count.min(stacks.stacks[from - 1].len());
+            move_crates(count, from - 1, to - 1, &mut stacks);
+        }
+    }
+    Ok(stacks
+        .stacks
+        .iter()
+        .filter_map(|column| column.iter().next())
+        .collect())
+}
+
+pub fn part1(input: &Input) -> Result<String> {
+    operate_crane(input, |count, from, to, stacks| {
+        for _ in 0..count {
+            if let Some(c) = stacks.stacks[from].pop_front() {
+                stacks.stacks[to].insert(0, c);
+            }
+        }
+    })
+}
+
+pub fn part2(input: &Input) -> Result<String> {
+    operate_crane(input, |count, from, to, stacks| {
+        for i in 0..count {
+            if let Some(c) = stacks.stacks[from].pop_front() {
+                stacks.stacks[to].insert(i, c);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{part1, part2};
+    use anyhow::Result;
+    use util::Input;
+
+    #[test]
+    pub fn test_part1() -> Result<()> {
+        let input = Input::from_lines([
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ]);
+        assert_eq!(part1(&input).unwrap(), "CMZ".to_string());
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_part2() -> Result<()> {
+        let input = Input::from_lines([
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+            "",
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 3",
+            "move 2 from 2 to 1",
+            "move 1 from 1 to 2",
+        ]);
+        assert_eq!(part2(&input).unwrap(), "MCD".to_string());
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
+}
@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    util::run(
+        "2022/day05/input",
+        aoc2022_day05::part1,
+        aoc2022_day05::part2,
+    )
+}
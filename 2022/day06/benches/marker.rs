@@ -0,0 +1,35 @@
+//! Compare finding markers with a window of toggled bits, as the solution does, with counting the
+//! unique characters of every window as it did before. Run with `cargo bench -p aoc2022-day06`.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use itertools::Itertools;
+
+use util::Input;
+
+/// Find a marker by counting the unique characters of every window
+fn find_marker_unique(input: &Input, marker_size: usize) -> Option<usize> {
+    input
+        .as_str()
+        .chars()
+        .collect_vec()
+        .windows(marker_size)
+        .position(|window| window.iter().unique().count() == marker_size)
+        .map(|index| index + marker_size)
+}
+
+fn find_marker(c: &mut Criterion) {
+    // The start-of-message marker only comes at the end, so almost every window is checked
+    let input = Input::from("abcdefghijklm".repeat(100) + "nopqrstuvwxyza\n");
+    let expected = find_marker_unique(&input, 14);
+    assert_eq!(expected, aoc2022_day06::find_marker(&input, 14).ok());
+    let mut group = c.benchmark_group("start-of-message marker");
+    group.bench_function("unique", |b| {
+        b.iter(|| find_marker_unique(black_box(&input), 14))
+    });
+    group.bench_function("BitSet", |b| {
+        b.iter(|| aoc2022_day06::find_marker(black_box(&input), 14))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, find_marker);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use util::bitset::{Alphabet, BitSet};
+use util::Input;
+
+util::register! {
+    year: 2022,
+    day: 6,
+    title: "Tuning Trouble",
+    part1: part1,
+    part2: part2,
+}
+
+/// Find the number of characters processed before the first marker of `marker_size` unique characters
+pub fn find_marker(input: &Input, marker_size: usize) -> Result<usize> {
+    let signal: Vec<usize> = input
+        .trim_trailing_newlines()
+        .as_str()
+        .chars()
+        .map(|c| {
+            Alphabet::LOWERCASE
+                .index(c)
+                .ok_or_else(|| anyhow!("Invalid character {:?}", c))
+        })
+        .try_collect()?;
+    // Toggle characters as they enter and leave the window, so the set holds the characters that
+    // occur an odd number of times, which are all of them once the set is as big as the window
+    let mut window = BitSet::<u64>::new();
+    for (index, &c) in signal.iter().enumerate() {
+        window.toggle(c);
+        if index >= marker_size {
+            window.toggle(signal[index - marker_size]);
+        }
+        if window.len() == marker_size {
+            return Ok(index + 1);
+        }
+    }
+    Err(anyhow!("No marker in input"))
+}
+
+pub fn part1(input: &Input) -> Result<usize> {
+    find_marker(input, 4)
+}
+
+pub fn part2(input: &Input) -> Result<usize> {
+    find_marker(input, 14)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{part1, part2};
+    use anyhow::Result;
+    use util::Input;
+
+    #[test]
+    pub fn test_part1() -> Result<()> {
+        for (input, expected_index) in [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+        ] {
+            let input = Input::from_lines([input]);
+            assert_eq!(part1(&input).unwrap(), expected_index);
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_part2() -> Result<()> {
+        for (input, expected_index) in [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+        ] {
+            let input = Input::from_lines([input]);
+            assert_eq!(part2(&input).unwrap(), expected_index);
+        }
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
+}
@@ -6,20 +6,17 @@ use std::str::FromStr;
 
 use util::Input;
 
-fn main() -> Result<()> {
-    let input = Input::load("day07/input")?;
-
-    println!("Part 1:");
-    println!("{}", part1(&input)?);
-
-    println!("Part 2:");
-    println!("{}", part2(&input)?);
-    Ok(())
+util::register! {
+    year: 2022,
+    day: 7,
+    title: "No Space Left On Device",
+    part1: part1,
+    part2: part2,
 }
 
 #[derive(Clone, Hash, Eq, PartialEq)]
-struct Path<'a> {
-    segments: Vec<&'a str>,
+pub struct Path<'a> {
+    pub segments: Vec<&'a str>,
 }
 
 impl<'a> Display for Path<'a> {
@@ -30,24 +27,20 @@ impl<'a> Display for Path<'a> {
 
 /// A path consisting of directories
 impl<'a> Path<'a> {
-    fn empty() -> Path<'a> {
+    pub fn empty() -> Path<'a> {
         Path { segments: vec![] }
     }
 
     /// Append a directory to this path, descending into it
-    fn descend(self, segment: &'a str) -> Path<'a> {
+    pub fn descend(self, segment: &'a str) -> Path<'a> {
         Path {
-            segments: self
-                .segments
-                .into_iter()
-                .chain([segment].into_iter())
-                .collect_vec(),
+            segments: self.segments.into_iter().chain([segment]).collect_vec(),
         }
     }
 
     /// Ascend a directory, dropping the last directory of this path
-    fn ascend(self) -> Path<'a> {
-        let len = 0.max(self.segments.len() - 1);
+    pub fn ascend(self) -> Path<'a> {
+        let len = self.segments.len().saturating_sub(1);
         Path {
             segments: self.segments.into_iter().take(len).collect_vec(),
         }
@@ -60,7 +53,7 @@ impl<'a> Path<'a> {
     /// /a
     /// /a/b
     /// /a/b/c
-    fn get_path_hierarchy(&self) -> Vec<String> {
+    pub fn get_path_hierarchy(&self) -> Vec<String> {
         let mut paths = Vec::new();
         let mut path = "/".to_string();
         for segment in &self.segments {
@@ -76,7 +69,7 @@ impl<'a> Path<'a> {
 /// Parse a directory traversal log, recording file and directory sizes on the way
 ///
 /// returns a map of all directories and the total size of the files and directories contained within
-fn get_directories(input: &Input) -> Result<HashMap<String, u32>> {
+pub fn get_directories(input: &Input) -> Result<HashMap<String, u32>> {
     let directories = input
         .as_lines()
         .try_fold(
@@ -86,7 +79,7 @@ fn get_directories(input: &Input) -> Result<HashMap<String, u32>> {
                 //  interpret the line
                 let path = match line {
                     // a 'cd' command
-                    cd if line.starts_with("$ cd") => match &cd[5..] {
+                    cd if line.starts_with("$ cd ") => match &cd[5..] {
                         ".." => path.ascend(),
                         "/" => Path::empty(),
                         subdir => path.descend(subdir),
@@ -101,10 +94,10 @@ fn get_directories(input: &Input) -> Result<HashMap<String, u32>> {
                             .ok_or_else(|| anyhow!("Invalid file description {}", file))?;
                         let size = u32::from_str(size)?;
                         for parent in path.get_path_hierarchy() {
-                            directories
-                                .entry(parent)
-                                .and_modify(|total| *total += size)
-                                .or_insert(size);
+                            let total = directories.entry(parent).or_insert(0u32);
+                            *total = total
+                                .checked_add(size)
+                                .ok_or_else(|| anyhow!("Directory size overflow at {}", file))?;
                         }
                         path
                     }
@@ -118,14 +111,27 @@ fn get_directories(input: &Input) -> Result<HashMap<String, u32>> {
     Ok(directories)
 }
 
-fn part1(input: &Input) -> Result<u32> {
-    let directories = get_directories(input)?;
-    Ok(directories.values().filter(|size| **size <= 100000).sum())
+/// Get the total size of the directories of at most 100000, failing if it doesn't fit in a u32
+pub fn total_of_small(directories: &HashMap<String, u32>) -> Result<u32> {
+    directories
+        .values()
+        .filter(|size| **size <= 100000)
+        .try_fold(0u32, |total, size| {
+            total
+                .checked_add(*size)
+                .ok_or_else(|| anyhow!("Total size overflow"))
+        })
+}
+
+pub fn part1(input: &Input) -> Result<u32> {
+    total_of_small(&get_directories(input)?)
 }
 
-fn part2(input: &Input) -> Result<u32> {
+pub fn part2(input: &Input) -> Result<u32> {
     let directories = get_directories(input)?;
-    let free_space = 70000000 - directories.get("/").cloned().unwrap_or(0);
+    let free_space = 70000000u32
+        .checked_sub(directories.get("/").cloned().unwrap_or(0))
+        .ok_or_else(|| anyhow!("Files don't fit on the disk"))?;
     let space_to_free = 30000000u32.saturating_sub(free_space);
     directories
         .values()
@@ -137,7 +143,7 @@ fn part2(input: &Input) -> Result<u32> {
 
 #[cfg(test)]
 mod test {
-    use crate::{part1, part2};
+    use crate::{part1, part2, total_of_small};
     use anyhow::Result;
     use util::Input;
 
@@ -202,4 +208,24 @@ mod test {
         assert_eq!(part2(&input).unwrap(), 24933642);
         Ok(())
     }
+
+    #[test]
+    pub fn test_total_overflow() {
+        // given directories that are too big to count, and ones that add up to more than a u32
+        let big = [
+            ("/".to_string(), u32::MAX),
+            ("/a/".to_string(), u32::MAX - 1),
+        ];
+        let small = (0..42950).map(|index| (format!("/{}/", index), 100000));
+
+        // expect only the small ones to count, and their total to overflow
+        assert_eq!(0, total_of_small(&big.iter().cloned().collect()).unwrap());
+        assert!(total_of_small(&big.into_iter().chain(small).collect()).is_err());
+    }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
 }
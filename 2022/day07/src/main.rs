@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    util::run(
+        "2022/day07/input",
+        aoc2022_day07::part1,
+        aoc2022_day07::part2,
+    )
+}
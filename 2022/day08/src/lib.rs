@@ -3,18 +3,16 @@ use itertools::Itertools;
 
 use util::Input;
 
-fn main() -> Result<()> {
-    let input = Input::load("day08/input")?;
-
-    println!("Part 1:");
-    println!("{}", part1(&input)?);
-
-    println!("Part 2:");
-    println!("{}", part2(&input)?);
-    Ok(())
+util::register! {
+    year: 2022,
+    day: 8,
+    title: "Treetop Tree House",
+    part1: part1,
+    part2: part2,
 }
 
-fn build_forest(input: &Input) -> Result<(Vec<u32>, usize, usize)> {
+/// Parse a forest into a flat list of tree heights, returned with the number of rows and columns
+pub fn build_forest(input: &Input) -> Result<(Vec<u32>, usize, usize)> {
     if input
         .trim_trailing_newlines()
         .as_lines()
@@ -28,14 +26,18 @@ fn build_forest(input: &Input) -> Result<(Vec<u32>, usize, usize)> {
     let forest = input
         .trim_trailing_newlines()
         .as_lines()
-        .flat_map(|line| line.chars().map(|c| c as u32 - '0' as u32))
-        .collect_vec();
+        .flat_map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).ok_or_else(|| anyhow!("Invalid tree {}", c)))
+        })
+        .try_collect::<_, Vec<_>, _>()?;
     let num_rows = input.trim_trailing_newlines().as_lines().count();
     let num_cols = forest.len() / num_rows;
     Ok((forest, num_rows, num_cols))
 }
 
-fn count_trees(forest: &[u32], iterator: impl Iterator<Item = usize>) -> Vec<usize> {
+/// Get the indexes of the trees visible when looking into the forest along the indexes of the iterator
+pub fn count_trees(forest: &[u32], iterator: impl Iterator<Item = usize>) -> Vec<usize> {
     iterator
         .fold((-1, Vec::new()), |state, index| {
             let (max_tree, mut trees) = state;
@@ -51,7 +53,7 @@ fn count_trees(forest: &[u32], iterator: impl Iterator<Item = usize>) -> Vec<usi
         .1
 }
 
-fn part1(input: &Input) -> Result<u32> {
+pub fn part1(input: &Input) -> Result<u32> {
     let (forest, num_rows, num_cols) = build_forest(input)?;
     let rows_iterator = (0..num_rows).flat_map(|row| -> [Box<dyn Iterator<Item = usize>>; 2] {
         let row_start = row * num_cols;
@@ -76,7 +78,8 @@ fn part1(input: &Input) -> Result<u32> {
         .count() as u32)
 }
 
-fn visible_trees(forest: &[u32], mut it: impl Iterator<Item = usize>) -> usize {
+/// Count the trees visible from the first tree of the iterator, looking along the rest of it
+pub fn visible_trees(forest: &[u32], mut it: impl Iterator<Item = usize>) -> usize {
     if let Some(tree_index) = it.next() {
         let tree = forest[tree_index];
         let mut seen = 0;
@@ -92,7 +95,7 @@ fn visible_trees(forest: &[u32], mut it: impl Iterator<Item = usize>) -> usize {
     }
 }
 
-fn part2(input: &Input) -> Result<u32> {
+pub fn part2(input: &Input) -> Result<u32> {
     let (forest, _num_rows, num_cols) = build_forest(input)?;
     Ok((0..forest.len())
         .map(|tree_index| {
@@ -131,4 +134,10 @@ mod test {
         assert_eq!(part2(&input).unwrap(), 8);
         Ok(())
     }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
 }
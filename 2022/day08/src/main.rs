@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    util::run(
+        "2022/day08/input",
+        aoc2022_day08::part1,
+        aoc2022_day08::part2,
+    )
+}
@@ -1,28 +1,26 @@
 use anyhow::{anyhow, bail, Result};
 use itertools::{process_results, Itertools};
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
 use std::str::FromStr;
 
-use util::Input;
+use util::sparse::{Point, SparseGrid};
+use util::{trace, Input};
 
-fn main() -> Result<()> {
-    let input = Input::load("day09/input")?;
-
-    println!("Part 1:");
-    println!("{}", part1(&input)?);
-
-    println!("Part 2:");
-    println!("{}", part2(&input)?);
-    Ok(())
+util::register! {
+    year: 2022,
+    day: 9,
+    title: "Rope Bridge",
+    part1: part1,
+    part2: part2,
 }
 
 /// A segment of rope, with a position in 2D-space
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Default, Debug)]
-struct RopeSegment(i32, i32);
+pub struct RopeSegment(pub i32, pub i32);
 
 /// A direction of movement in 2D-space
 #[derive(Copy, Clone)]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Left,
@@ -31,25 +29,30 @@ enum Direction {
 
 impl Direction {
     /// Convert a string to a series of directional movements
-    fn directions(instruction: &str) -> Result<impl Iterator<Item = Direction>> {
+    pub fn directions(instruction: &str) -> Result<impl Iterator<Item = Direction>> {
         let (direction, steps) = instruction
             .splitn(2, ' ')
             .collect_tuple()
             .ok_or_else(|| anyhow!("Invalid instruction {}", instruction))?;
         let steps = usize::from_str(steps)?;
         match direction {
-            "U" => Ok(std::iter::repeat(Direction::Up).take(steps)),
-            "D" => Ok(std::iter::repeat(Direction::Down).take(steps)),
-            "L" => Ok(std::iter::repeat(Direction::Left).take(steps)),
-            "R" => Ok(std::iter::repeat(Direction::Right).take(steps)),
+            "U" => Ok(std::iter::repeat_n(Direction::Up, steps)),
+            "D" => Ok(std::iter::repeat_n(Direction::Down, steps)),
+            "L" => Ok(std::iter::repeat_n(Direction::Left, steps)),
+            "R" => Ok(std::iter::repeat_n(Direction::Right, steps)),
             _ => bail!("Invalid instruction {}", instruction),
         }
     }
 }
 
 impl RopeSegment {
+    /// Get the point in a grid the segment is at
+    pub fn point(&self) -> Point {
+        Point::new(self.0.into(), self.1.into())
+    }
+
     /// Step one step in a direction
-    fn step(&self, direction: Direction) -> RopeSegment {
+    pub fn step(&self, direction: Direction) -> RopeSegment {
         match direction {
             Direction::Up => RopeSegment(self.0, self.1 - 1),
             Direction::Down => RopeSegment(self.0, self.1 + 1),
@@ -63,7 +66,7 @@ impl RopeSegment {
     /// If the other segment is more than 1 step away from this segment
     /// step towards it, stepping diagonally if the rope segment is
     /// neither on the same X or Y coordinate as this segment.
-    fn step_after(&self, other: RopeSegment) -> RopeSegment {
+    pub fn step_after(&self, other: RopeSegment) -> RopeSegment {
         let x_distance = other.0 - self.0;
         let y_distance = other.1 - self.1;
         if x_distance.abs() <= 1 && y_distance.abs() <= 1 {
@@ -83,19 +86,22 @@ impl RopeSegment {
 
 /// Move a rope according to a series of directions and return the number of different positions
 /// the rope's tail has visited
-fn move_rope(input: &Input, rope_length: usize) -> Result<u32> {
-    Ok(process_results(
+pub fn move_rope(input: &Input, rope_length: usize) -> Result<u32> {
+    let visited = process_results(
         input
             .trim_trailing_newlines()
             .as_lines()
-            .map(Direction::directions),
+            .map(|instruction| {
+                trace::debug!(instruction, "Moving rope");
+                Direction::directions(instruction)
+            }),
         |instructions| {
             instructions.flatten().fold(
                 (
-                    std::iter::repeat(RopeSegment::default())
-                        .take(rope_length)
-                        .collect(), // Rope
-                    [RopeSegment::default()].into_iter().collect::<HashSet<_>>(), // Positions visited by the tail
+                    std::iter::repeat_n(RopeSegment::default(), rope_length).collect(), // Rope
+                    [(RopeSegment::default().point(), ())]
+                        .into_iter()
+                        .collect::<SparseGrid<_>>(), // Positions visited by the tail
                 ),
                 |state, direction| {
                     let (rope, mut tail_positions): (VecDeque<_>, _) = state;
@@ -113,21 +119,26 @@ fn move_rope(input: &Input, rope_length: usize) -> Result<u32> {
                             new_rope
                         });
                     // Record the tail's position
-                    tail_positions.insert(rope[rope.len() - 1]);
+                    trace::trace!(head = ?rope[0], tail = ?rope[rope.len() - 1], "Moved rope");
+                    tail_positions.insert(rope[rope.len() - 1].point(), ());
                     (rope, tail_positions)
                 },
             )
         },
     )?
-    .1
-    .len() as u32)
+    .1;
+    trace::trace!(
+        "Positions visited by the tail:\n{}",
+        visited.render(|position| if position.is_some() { '#' } else { '.' })
+    );
+    Ok(visited.len() as u32)
 }
 
-fn part1(input: &Input) -> Result<u32> {
+pub fn part1(input: &Input) -> Result<u32> {
     move_rope(input, 2)
 }
 
-fn part2(input: &Input) -> Result<u32> {
+pub fn part2(input: &Input) -> Result<u32> {
     move_rope(input, 10)
 }
 
@@ -150,4 +161,10 @@ mod test {
         assert_eq!(part2(&input).unwrap(), 1);
         Ok(())
     }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
 }
@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    util::run(
+        "2022/day09/input",
+        aoc2022_day09::part1,
+        aoc2022_day09::part2,
+    )
+}
@@ -0,0 +1,119 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use util::parse::{literal, number, Parser};
+use util::Input;
+
+util::register! {
+    year: 2022,
+    day: 10,
+    title: "Cathode-Ray Tube",
+    part1: part1,
+    part2: part2,
+}
+
+/// An instruction of the CPU
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+/// Get the parser of an instruction
+pub fn instruction() -> impl Parser<Instruction> {
+    literal("noop")
+        .map(|_| Instruction::Noop)
+        .or(literal("addx ")
+            .ignore_then(number())
+            .map(Instruction::Addx))
+}
+
+/// Run a program and return the value of the X register during every cycle
+pub fn run_computation(input: &Input) -> Result<Vec<i32>> {
+    let parser = instruction();
+    let timeline = input
+        .parse_lines(&parser)?
+        .into_iter()
+        .try_fold(
+            (1i32, Vec::new()),
+            |mut state, instruction| match instruction {
+                Instruction::Noop => {
+                    state.1.push(state.0);
+                    Ok(state)
+                }
+                Instruction::Addx(amount) => {
+                    state.1.push(state.0);
+                    state.1.push(state.0);
+                    match state.0.checked_add(amount) {
+                        Some(register) => Ok((register, state.1)),
+                        None => Err(anyhow!("Register overflow at addx {}", amount)),
+                    }
+                }
+            },
+        )?
+        .1;
+    Ok(timeline)
+}
+
+pub fn part1(input: &Input) -> Result<i32> {
+    let timeline = run_computation(input)?;
+    timeline
+        .iter()
+        .enumerate()
+        .skip(19)
+        .step_by(40)
+        .try_fold(0i32, |total, (index, value)| {
+            i32::try_from(index + 1)
+                .ok()
+                .and_then(|cycle| cycle.checked_mul(*value))
+                .and_then(|strength| total.checked_add(strength))
+                .ok_or_else(|| anyhow!("Signal strength overflow at cycle {}", index + 1))
+        })
+}
+
+pub fn part2(input: &Input) -> Result<String> {
+    let timeline = run_computation(input)?;
+    Ok(timeline
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let pixel = (index % 40) as i64;
+            if (pixel - *value as i64).abs() <= 1 {
+                "#"
+            } else {
+                "."
+            }
+        })
+        .chunks(40)
+        .into_iter()
+        .map(|mut chunk| chunk.join(""))
+        .join("\n")
+        + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{part1, part2};
+    use anyhow::Result;
+    use util::Input;
+
+    #[test]
+    pub fn test_part1() -> Result<()> {
+        let input = Input::load("test_input")?;
+        assert_eq!(part1(&input).unwrap(), 13140);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_part2() -> Result<()> {
+        let input = Input::load("test_input")?;
+        util::assert_snapshot!("part2", part2(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
+}
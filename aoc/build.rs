@@ -0,0 +1,34 @@
+//! Declares every day the runner depends on as an extern crate, making sure the days are linked
+//! into the runner so their solutions end up in the registry.
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let manifest = fs::read_to_string("Cargo.toml").expect("Could not read Cargo.toml");
+    let days: String = manifest
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(name, _)| name.trim())
+        .filter(|name| is_day(name))
+        .map(|day| format!("extern crate {};\n", day.replace('-', "_")))
+        .collect();
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    fs::write(Path::new(&out_dir).join("days.rs"), days).expect("Could not write days.rs");
+}
+
+/// Check if a dependency is a day, i.e. named aocYYYY-dayNN
+fn is_day(name: &str) -> bool {
+    match name
+        .strip_prefix("aoc")
+        .and_then(|name| name.split_once("-day"))
+    {
+        Some((year, day)) => {
+            year.len() == 4
+                && day.len() == 2
+                && year.chars().chain(day.chars()).all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,234 @@
+//! Answers to the parts that have been solved, recorded in `answers.toml` by year and day
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use util::Input;
+
+use crate::inspect::hash;
+use crate::isolate::Limits;
+use crate::report::Outcome;
+use crate::run::{input_path, run_solution, Days};
+
+/// The file answers are recorded in, relative to the root of the workspace
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+/// The recorded answers for a day
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    pub year: u16,
+    pub day: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    /// The SHA-256 hash of the input the answers are for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_hash: Option<String>,
+}
+
+impl DayAnswers {
+    /// The number of stars earned, i.e. the number of parts with a recorded answer
+    pub fn stars(&self) -> usize {
+        [&self.part1, &self.part2]
+            .into_iter()
+            .filter(|answer| answer.is_some())
+            .count()
+    }
+}
+
+/// All recorded answers
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    answers: Vec<DayAnswers>,
+}
+
+impl Answers {
+    /// Load answers from a file, treating a missing file as no answers having been recorded
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(answers) => toml::from_str(&answers)
+                .with_context(|| format!("Invalid answers in {}", path.display())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(error).with_context(|| format!("Could not read {}", path.display())),
+        }
+    }
+
+    /// Save the answers to a file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(fs::write(path, toml::to_string(self)?)?)
+    }
+
+    /// Get the recorded answers for a day
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayAnswers> {
+        self.answers
+            .iter()
+            .find(|answers| answers.year == year && answers.day == day)
+    }
+
+    /// Record the answer to a part, replacing any previously recorded answer
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: String) {
+        let answers = self.entry(year, day);
+        match part {
+            1 => answers.part1 = Some(answer),
+            _ => answers.part2 = Some(answer),
+        }
+    }
+
+    /// Record the hash of the input the answers of a day are for, after recording the answers to
+    /// some parts. Unless the input is the same, the answers to the other parts are for another
+    /// input and are dropped.
+    pub fn record_input_hash(&mut self, year: u16, day: u8, hash: String, parts: &[u8]) {
+        let answers = self.entry(year, day);
+        if answers.input_hash.as_ref() != Some(&hash) {
+            if !parts.contains(&1) {
+                answers.part1 = None;
+            }
+            if !parts.contains(&2) {
+                answers.part2 = None;
+            }
+        }
+        answers.input_hash = Some(hash);
+    }
+
+    /// Get the answers for a day to record them in, keeping the days ordered
+    fn entry(&mut self, year: u16, day: u8) -> &mut DayAnswers {
+        let index = match self
+            .answers
+            .binary_search_by_key(&(year, day), |answers| (answers.year, answers.day))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.answers.insert(
+                    index,
+                    DayAnswers {
+                        year,
+                        day,
+                        ..Default::default()
+                    },
+                );
+                index
+            }
+        };
+        &mut self.answers[index]
+    }
+
+    /// The number of stars earned for a day
+    pub fn stars(&self, year: u16, day: u8) -> usize {
+        self.get(year, day).map_or(0, DayAnswers::stars)
+    }
+}
+
+/// Run the days and record the answers of all parts that succeed
+pub fn record(days: Days, year: Option<u16>, limits: &Limits) -> Result<()> {
+    let mut answers = Answers::load(ANSWERS_PATH)?;
+    for solution in days.solutions(year)? {
+        let mut recorded = Vec::new();
+        for result in run_solution(solution, limits) {
+            match result.outcome {
+                Outcome::Answer(answer) => {
+                    println!(
+                        "Recording {} day {} part {}",
+                        result.year, result.day, result.part
+                    );
+                    answers.record(result.year, result.day, result.part, answer);
+                    recorded.push(result.part);
+                }
+                outcome => println!(
+                    "Not recording {} day {} part {}: {}",
+                    result.year,
+                    result.day,
+                    result.part,
+                    outcome.error().unwrap_or_default()
+                ),
+            }
+        }
+        if !recorded.is_empty() {
+            let input = Input::load(input_path(solution))?;
+            let hash = hash(input.as_str());
+            answers.record_input_hash(solution.year, solution.day, hash, &recorded);
+        }
+    }
+    answers.save(ANSWERS_PATH)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Answers;
+
+    #[test]
+    fn test_record() {
+        // given some recorded answers, out of order
+        let mut answers = Answers::default();
+        answers.record(2022, 7, 1, "95437".to_string());
+        answers.record(2022, 1, 2, "45000".to_string());
+        answers.record(2022, 1, 1, "24000".to_string());
+        answers.record_input_hash(2022, 7, "abc".to_string(), &[1]);
+
+        // expect stars for every recorded part
+        assert_eq!(2, answers.stars(2022, 1));
+        assert_eq!(1, answers.stars(2022, 7));
+        assert_eq!(0, answers.stars(2022, 2));
+        assert_eq!(
+            Some("45000"),
+            answers.get(2022, 1).unwrap().part2.as_deref()
+        );
+
+        // and the answers to be stored ordered by year and day
+        assert_eq!(
+            toml::to_string(&answers).unwrap(),
+            "[[answer]]\n\
+             year = 2022\n\
+             day = 1\n\
+             part1 = \"24000\"\n\
+             part2 = \"45000\"\n\
+             \n\
+             [[answer]]\n\
+             year = 2022\n\
+             day = 7\n\
+             part1 = \"95437\"\n\
+             input_hash = \"abc\"\n"
+        );
+    }
+
+    #[test]
+    fn test_record_for_other_input() {
+        // given both answers for an input
+        let mut answers = Answers::default();
+        answers.record(2022, 1, 1, "24000".to_string());
+        answers.record(2022, 1, 2, "45000".to_string());
+        answers.record_input_hash(2022, 1, "abc".to_string(), &[1, 2]);
+
+        // when only part 1 is recorded again for the same input
+        answers.record(2022, 1, 1, "24000".to_string());
+        answers.record_input_hash(2022, 1, "abc".to_string(), &[1]);
+
+        // expect part 2 to be kept
+        assert_eq!(2, answers.stars(2022, 1));
+
+        // when only part 1 is recorded for another input
+        answers.record(2022, 1, 1, "70698".to_string());
+        answers.record_input_hash(2022, 1, "def".to_string(), &[1]);
+
+        // expect the answer to part 2 for the old input to be dropped
+        let day = answers.get(2022, 1).unwrap();
+        assert_eq!(Some("70698"), day.part1.as_deref());
+        assert_eq!(None, day.part2);
+        assert_eq!(Some("def"), day.input_hash.as_deref());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // given some recorded answers, including a multi-line one
+        let mut answers = Answers::default();
+        answers.record(2022, 10, 2, "#..#\n.##.\n".to_string());
+
+        // expect them to survive being stored
+        let stored = toml::to_string(&answers).unwrap();
+        assert_eq!(answers, toml::from_str(&stored).unwrap());
+    }
+}
@@ -0,0 +1,82 @@
+//! Random puzzle input generators
+//!
+//! Every generator produces text in the same format as the real puzzle input for its day, but of
+//! an arbitrary size, so the solutions can be stress tested and benchmarked.
+use anyhow::{anyhow, Result};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod y2022;
+
+/// The random number generator handed to the generators.
+///
+/// ChaCha is used instead of `StdRng` since its output is guaranteed to stay the same between
+/// releases, so a seed always reproduces the same input.
+pub type InputRng = ChaCha8Rng;
+
+/// A function generating a puzzle input of roughly `size` records
+pub type Generator = fn(rng: &mut InputRng, size: usize) -> String;
+
+/// Get the generator for a day
+pub fn generator(year: u16, day: u8) -> Option<Generator> {
+    match (year, day) {
+        (2022, 1) => Some(y2022::day01::generate),
+        (2022, 2) => Some(y2022::day02::generate),
+        (2022, 3) => Some(y2022::day03::generate),
+        (2022, 4) => Some(y2022::day04::generate),
+        (2022, 5) => Some(y2022::day05::generate),
+        (2022, 6) => Some(y2022::day06::generate),
+        (2022, 7) => Some(y2022::day07::generate),
+        (2022, 8) => Some(y2022::day08::generate),
+        (2022, 9) => Some(y2022::day09::generate),
+        (2022, 10) => Some(y2022::day10::generate),
+        _ => None,
+    }
+}
+
+/// Generate a puzzle input for a day using a seeded random generator
+pub fn generate(year: u16, day: u8, size: usize, seed: u64) -> Result<String> {
+    let generator =
+        generator(year, day).ok_or_else(|| anyhow!("No generator for {} day {}", year, day))?;
+    Ok(generator(&mut InputRng::seed_from_u64(seed), size))
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use util::{registry, Input};
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for day in 1..=10 {
+            // given two inputs generated with the same seed
+            let first = generate(2022, day, 100, 42).unwrap();
+            let second = generate(2022, day, 100, 42).unwrap();
+
+            // expect them to be equal
+            assert_eq!(first, second, "day {}", day);
+        }
+    }
+
+    #[test]
+    fn test_solvable() {
+        for day in 1..=10 {
+            // given an input generated for a day, and its solution
+            let input = Input::from(generate(2022, day, 100, 42).unwrap());
+            let solution = registry::find(2022, day).unwrap();
+
+            // expect both parts to solve it
+            for part in [solution.part1, solution.part2] {
+                let answer = part.unwrap()(&input);
+                assert!(answer.is_ok(), "day {}: {:?}", day, answer);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        // expect generating input for a day without a generator to fail
+        assert!(generate(2022, 26, 100, 42).is_err());
+        assert!(generate(2015, 1, 100, 42).is_err());
+    }
+}
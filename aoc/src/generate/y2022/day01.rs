@@ -0,0 +1,33 @@
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+/// Generate calorie lists for `size` elves
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut input = String::new();
+    for elf in 0..size {
+        if elf > 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.gen_range(1..=15) {
+            input += &format!("{}\n", rng.gen_range(1000..=60000));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        // given a generated input for 10 elves
+        let input = generate(&mut InputRng::seed_from_u64(1), 10);
+
+        // expect the elves to be separated by empty lines
+        assert_eq!(input.trim_end().split("\n\n").count(), 10);
+    }
+}
@@ -0,0 +1,35 @@
+use rand::seq::SliceRandom;
+
+use crate::generate::InputRng;
+
+/// Generate a strategy guide with `size` rounds
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                ['A', 'B', 'C'].choose(rng).unwrap(),
+                ['X', 'Y', 'Z'].choose(rng).unwrap()
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        // given a generated input with 100 rounds
+        let input = generate(&mut InputRng::seed_from_u64(1), 100);
+
+        // expect every line to be a valid round
+        assert_eq!(input.lines().count(), 100);
+        assert!(input
+            .lines()
+            .all(|line| matches!(line.as_bytes(), [b'A'..=b'C', b' ', b'X'..=b'Z'])));
+    }
+}
@@ -0,0 +1,76 @@
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generate `size` groups of three rucksacks.
+///
+/// The compartments of every rucksack share exactly one item and the rucksacks of a group share
+/// exactly one item, the badge.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut items = ITEMS.chars().collect_vec();
+        items.shuffle(rng);
+        // Every elf of the group draws from its own items, so only the badge is shared
+        let badge = items.pop().unwrap();
+        for elf_items in items.chunks(items.len() / 3) {
+            input += &rucksack(rng, badge, elf_items);
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// Pack a rucksack containing the badge and some of the items
+fn rucksack(rng: &mut InputRng, badge: char, items: &[char]) -> String {
+    let (first_items, second_items) = items.split_at(items.len() / 2);
+    // The item in both compartments is either the badge or one of the elf's own items
+    let shared = *[badge, first_items[0]].choose(rng).unwrap();
+    let size = rng.gen_range(3..=16);
+    let mut first = vec![shared, badge];
+    first.extend((first.len()..size).map(|_| *first_items[1..].choose(rng).unwrap()));
+    first.shuffle(rng);
+    let mut second = vec![shared];
+    second.extend((second.len()..size).map(|_| *second_items.choose(rng).unwrap()));
+    second.shuffle(rng);
+    first.into_iter().chain(second).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::generate::InputRng;
+    use itertools::Itertools;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_generate() {
+        // given a generated input for 20 groups
+        let input = generate(&mut InputRng::seed_from_u64(1), 20);
+        let rucksacks = input.lines().collect_vec();
+        assert_eq!(rucksacks.len(), 60);
+
+        // expect the compartments of every rucksack to share exactly one item
+        for rucksack in &rucksacks {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            let first: HashSet<char> = first.chars().collect();
+            let second: HashSet<char> = second.chars().collect();
+            assert_eq!(first.intersection(&second).count(), 1, "{}", rucksack);
+        }
+
+        // and every group to share exactly one item
+        for group in rucksacks.chunks(3) {
+            let shared = group
+                .iter()
+                .map(|rucksack| rucksack.chars().collect::<HashSet<_>>())
+                .reduce(|shared, items| shared.intersection(&items).cloned().collect())
+                .unwrap();
+            assert_eq!(shared.len(), 1);
+        }
+    }
+}
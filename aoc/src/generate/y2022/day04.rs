@@ -0,0 +1,36 @@
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+/// Generate `size` pairs of section assignments
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{},{}\n", sections(rng), sections(rng)))
+        .collect()
+}
+
+/// Generate a range of sections
+fn sections(rng: &mut InputRng) -> String {
+    let start = rng.gen_range(1..=99);
+    format!("{}-{}", start, rng.gen_range(start..=99))
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        // given a generated input with 100 pairs
+        let input = generate(&mut InputRng::seed_from_u64(1), 100);
+
+        // expect every range to start before it ends
+        assert_eq!(input.lines().count(), 100);
+        for range in input.lines().flat_map(|line| line.split(',')) {
+            let (start, end) = range.split_once('-').unwrap();
+            assert!(start.parse::<u32>().unwrap() <= end.parse::<u32>().unwrap());
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use itertools::Itertools;
+use rand::seq::IteratorRandom;
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+const NUM_STACKS: usize = 9;
+
+/// Generate a drawing of crate stacks followed by `size` moves.
+///
+/// Moves never take more crates from a stack than it holds at that point.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut stacks = (0..NUM_STACKS)
+        .map(|_| {
+            (0..rng.gen_range(1..=8))
+                .map(|_| rng.gen_range('A'..='Z'))
+                .collect_vec()
+        })
+        .collect_vec();
+    let mut input = drawing(&stacks);
+    input.push('\n');
+    for _ in 0..size {
+        let from = (0..NUM_STACKS)
+            .filter(|stack| !stacks[*stack].is_empty())
+            .choose(rng)
+            .unwrap();
+        let to = (0..NUM_STACKS)
+            .filter(|stack| *stack != from)
+            .choose(rng)
+            .unwrap();
+        let count = rng.gen_range(1..=stacks[from].len());
+        let remaining = stacks[from].len() - count;
+        let crates = stacks[from].split_off(remaining);
+        stacks[to].extend(crates);
+        input += &format!("move {} from {} to {}\n", count, from + 1, to + 1);
+    }
+    input
+}
+
+/// Draw stacks of crates, with the bottom crate first in every stack
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for row in (0..height).rev() {
+        drawing += &stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .join(" ");
+        drawing.push('\n');
+    }
+    drawing += &(1..=stacks.len()).map(|i| format!(" {} ", i)).join(" ");
+    drawing.push('\n');
+    drawing
+}
+
+#[cfg(test)]
+mod test {
+    use super::{drawing, generate, NUM_STACKS};
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+    use util::ints::ints_array;
+
+    #[test]
+    fn test_drawing() {
+        // given some stacks
+        let stacks = [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+
+        // expect them to be drawn like in the puzzle
+        assert_eq!(
+            drawing(&stacks),
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n"
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        // given a generated input with 100 moves
+        let input = generate(&mut InputRng::seed_from_u64(1), 100);
+
+        // expect the drawing and the moves to be separated by an empty line
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+        assert_eq!(moves.lines().count(), 100);
+
+        // and every move to take at most the crates on its stack at that point
+        let mut heights = [0; NUM_STACKS];
+        for row in drawing.lines() {
+            for (stack, c) in row.chars().skip(1).step_by(4).enumerate() {
+                heights[stack] += c.is_ascii_uppercase() as usize;
+            }
+        }
+        for line in moves.lines() {
+            let [count, from, to] = ints_array::<usize, 3>(line).unwrap();
+            assert!(count <= heights[from - 1], "{}", line);
+            heights[from - 1] -= count;
+            heights[to - 1] += count;
+        }
+    }
+}
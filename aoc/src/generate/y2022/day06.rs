@@ -0,0 +1,123 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// Generate a datastream buffer of `size` characters with a start-of-packet and a
+/// start-of-message marker planted in it.
+///
+/// The stream leading up to the start-of-packet marker only uses three different letters and
+/// the stream leading up to the start-of-message marker only uses thirteen, so no window before
+/// them can be a marker on its own. The letter right before each marker repeats its first letter,
+/// so neither can the windows overlapping the start of a marker.
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let (stream, _) = planted(rng, size);
+    stream
+}
+
+/// Generate a datastream buffer along with the positions the markers are planted at
+fn planted(rng: &mut InputRng, size: usize) -> (String, [usize; 2]) {
+    let size = size.max(40);
+    let packet_marker = rng.gen_range(0..size / 2);
+    let message_marker = rng.gen_range(packet_marker + 5..=size - 14);
+    let alphabet = distinct_letters(rng, LETTERS, 13);
+    let mut stream = Vec::with_capacity(size + 1);
+    let packet = marker(rng, &alphabet[..3], &alphabet, 4);
+    stream.extend(noise(rng, &alphabet[..3], packet[0], packet_marker));
+    stream.extend(packet);
+    let message = marker(rng, &alphabet, LETTERS, 14);
+    let count = message_marker - stream.len();
+    stream.extend(noise(rng, &alphabet, message[0], count));
+    stream.extend(message);
+    stream.extend(random_letters(rng, LETTERS, size - stream.len()));
+    stream.push(b'\n');
+    let stream = String::from_utf8(stream).unwrap();
+    (stream, [packet_marker, message_marker])
+}
+
+/// Pick `count` distinct letters, starting with one of the `first` letters and followed by any of
+/// the `rest`
+fn marker(rng: &mut InputRng, first: &[u8], rest: &[u8], count: usize) -> Vec<u8> {
+    let first = *first.choose(rng).unwrap();
+    let rest = rest
+        .iter()
+        .cloned()
+        .filter(|&c| c != first)
+        .collect::<Vec<_>>();
+    let mut marker = vec![first];
+    marker.extend(distinct_letters(rng, &rest, count - 1));
+    marker
+}
+
+/// Pick `count` random letters of an alphabet, ending with the `last` letter
+fn noise(rng: &mut InputRng, alphabet: &[u8], last: u8, count: usize) -> Vec<u8> {
+    let mut noise = random_letters(rng, alphabet, count);
+    if let Some(c) = noise.last_mut() {
+        *c = last;
+    }
+    noise
+}
+
+/// Pick `count` random letters of an alphabet
+fn random_letters(rng: &mut InputRng, alphabet: &[u8], count: usize) -> Vec<u8> {
+    (0..count).map(|_| *alphabet.choose(rng).unwrap()).collect()
+}
+
+/// Pick `count` distinct random letters of an alphabet, in random order
+fn distinct_letters(rng: &mut InputRng, alphabet: &[u8], count: usize) -> Vec<u8> {
+    let mut letters = alphabet
+        .choose_multiple(rng, count)
+        .cloned()
+        .collect::<Vec<_>>();
+    letters.shuffle(rng);
+    letters
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, planted};
+    use crate::generate::InputRng;
+    use itertools::Itertools;
+    use rand::SeedableRng;
+    use util::Input;
+
+    #[test]
+    fn test_planted_markers() {
+        for seed in 0..100 {
+            // given a generated input and where its markers are planted
+            let (stream, [packet, message]) = planted(&mut InputRng::seed_from_u64(seed), 100);
+            let input = Input::from(stream);
+
+            // expect the planted markers to be the first ones
+            assert_eq!(
+                packet + 4,
+                aoc2022_day06::part1(&input).unwrap(),
+                "seed {}",
+                seed
+            );
+            assert_eq!(
+                message + 14,
+                aoc2022_day06::part2(&input).unwrap(),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        // given a generated input of 1000 characters
+        let input = generate(&mut InputRng::seed_from_u64(1), 1000);
+        let stream = input.trim_end().chars().collect_vec();
+        assert_eq!(stream.len(), 1000);
+
+        // expect both markers to be present
+        for marker_size in [4, 14] {
+            assert!(stream
+                .windows(marker_size)
+                .any(|window| window.iter().unique().count() == marker_size));
+        }
+    }
+}
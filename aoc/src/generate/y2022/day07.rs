@@ -0,0 +1,118 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::generate::InputRng;
+
+/// Total size of all files, small enough to fit on the 70000000 disk
+const TOTAL_SIZE: usize = 60000000;
+
+#[derive(Default)]
+struct Directory {
+    name: String,
+    subdirectories: Vec<usize>,
+    files: Vec<(u32, String)>,
+    names: HashSet<String>,
+}
+
+/// Generate a terminal transcript exploring a file system with `size` files
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let mut directories = vec![Directory::default()];
+    for index in 1..(size / 4).max(2) {
+        let parent = rng.gen_range(0..index);
+        let name = unique_name(rng, &mut directories[parent].names, false);
+        directories[parent].subdirectories.push(index);
+        directories.push(Directory {
+            name,
+            ..Default::default()
+        });
+    }
+    let max_file_size = (TOTAL_SIZE / size.max(1)).max(1) as u32;
+    for _ in 0..size {
+        let index = rng.gen_range(0..directories.len());
+        let directory = &mut directories[index];
+        let name = unique_name(rng, &mut directory.names, true);
+        directory
+            .files
+            .push((rng.gen_range(max_file_size / 2..=max_file_size), name));
+    }
+    let mut transcript = vec!["$ cd /".to_string()];
+    explore(rng, &directories, 0, &mut transcript);
+    transcript.join("\n") + "\n"
+}
+
+/// Generate a name that isn't already used in a directory
+fn unique_name(rng: &mut InputRng, names: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.gen_range(1..=8))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect();
+        if extension && rng.gen_bool(0.5) {
+            name += [".txt", ".dat", ".log", ".lst", ".ext"]
+                .choose(rng)
+                .unwrap();
+        }
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// List the contents of a directory and then descend into all its subdirectories
+fn explore(
+    rng: &mut InputRng,
+    directories: &[Directory],
+    index: usize,
+    transcript: &mut Vec<String>,
+) {
+    let directory = &directories[index];
+    transcript.push("$ ls".to_string());
+    let mut listing = directory
+        .subdirectories
+        .iter()
+        .map(|subdirectory| format!("dir {}", directories[*subdirectory].name))
+        .chain(
+            directory
+                .files
+                .iter()
+                .map(|(size, name)| format!("{} {}", size, name)),
+        )
+        .collect::<Vec<_>>();
+    listing.shuffle(rng);
+    transcript.extend(listing);
+    for subdirectory in &directory.subdirectories {
+        transcript.push(format!("$ cd {}", directories[*subdirectory].name));
+        explore(rng, directories, *subdirectory, transcript);
+        transcript.push("$ cd ..".to_string());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{generate, TOTAL_SIZE};
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        // given a generated input with 100 files
+        let input = generate(&mut InputRng::seed_from_u64(1), 100);
+        let files = input
+            .lines()
+            .filter_map(|line| line.split_once(' ')?.0.parse::<usize>().ok())
+            .collect::<Vec<_>>();
+
+        // expect it to list 100 files fitting on the disk
+        assert_eq!(files.len(), 100);
+        assert!(files.into_iter().sum::<usize>() <= TOTAL_SIZE);
+
+        // and to return to the root directory after exploring it
+        let depth = input.lines().fold(0, |depth, line| match line {
+            "$ cd /" => 0,
+            "$ cd .." => depth - 1,
+            cd if cd.starts_with("$ cd ") => depth + 1,
+            _ => depth,
+        });
+        assert_eq!(depth, 0);
+    }
+}
@@ -0,0 +1,34 @@
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+/// Generate a forest of `size` by `size` trees
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size).map(|_| rng.gen_range('0'..='9')).collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        // given a generated forest of 50 by 50 trees
+        let input = generate(&mut InputRng::seed_from_u64(1), 50);
+
+        // expect it to be square and only contain tree heights
+        assert_eq!(input.lines().count(), 50);
+        assert!(input
+            .lines()
+            .all(|line| line.len() == 50 && line.chars().all(|c| c.is_ascii_digit())));
+    }
+}
@@ -0,0 +1,38 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+/// Generate `size` motions of the rope's head
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                ["U", "D", "L", "R"].choose(rng).unwrap(),
+                rng.gen_range(1..=20)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        // given a generated input with 100 motions
+        let input = generate(&mut InputRng::seed_from_u64(1), 100);
+
+        // expect every line to be a direction and a number of steps
+        assert_eq!(input.lines().count(), 100);
+        for line in input.lines() {
+            let (direction, steps) = line.split_once(' ').unwrap();
+            assert!(["U", "D", "L", "R"].contains(&direction));
+            assert!(steps.parse::<u32>().unwrap() > 0);
+        }
+    }
+}
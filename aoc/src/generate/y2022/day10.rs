@@ -0,0 +1,39 @@
+use rand::Rng;
+
+use crate::generate::InputRng;
+
+/// Generate a program of `size` instructions
+pub fn generate(rng: &mut InputRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            if rng.gen_bool(0.3) {
+                "noop\n".to_string()
+            } else {
+                format!("addx {}\n", rng.gen_range(-20..=20))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::generate;
+    use crate::generate::InputRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_generate() {
+        // given a generated program with 100 instructions
+        let input = generate(&mut InputRng::seed_from_u64(1), 100);
+
+        // expect every line to be a valid instruction
+        assert_eq!(input.lines().count(), 100);
+        for line in input.lines() {
+            match line.split_once(' ') {
+                Some(("addx", amount)) => assert!(amount.parse::<i32>().is_ok()),
+                None => assert_eq!(line, "noop"),
+                _ => panic!("Invalid instruction {}", line),
+            }
+        }
+    }
+}
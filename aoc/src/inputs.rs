@@ -0,0 +1,81 @@
+//! Encryption of the puzzle inputs, so they can be committed to a public repository
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use std::fs;
+use std::path::Path;
+
+use util::encryption::{self, Key, KEY_VARIABLE};
+use util::Input;
+
+use crate::run::{input_path, Days};
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Encrypt the inputs of the days to input.age, with the key in AOC_INPUT_KEY or .env
+    Encrypt {
+        /// The day to encrypt, or "all" to encrypt every implemented day
+        #[arg(default_value = "all")]
+        days: Days,
+    },
+    /// Decrypt the inputs of the days from input.age, with the key in AOC_INPUT_KEY or .env
+    Decrypt {
+        /// The day to decrypt, or "all" to decrypt every implemented day
+        #[arg(default_value = "all")]
+        days: Days,
+    },
+    /// Generate a new key to encrypt inputs with
+    Key,
+}
+
+pub fn inputs(command: Command, year: Option<u16>) -> Result<()> {
+    match command {
+        Command::Encrypt { days } => encrypt(days, year),
+        Command::Decrypt { days } => decrypt(days, year),
+        Command::Key => {
+            eprintln!("Add this key to .env as {}=\"...\"", KEY_VARIABLE);
+            println!("{}", Key::generate());
+            Ok(())
+        }
+    }
+}
+
+/// Encrypt the plain inputs of the days, leaving encrypted inputs that haven't changed as they are
+fn encrypt(days: Days, year: Option<u16>) -> Result<()> {
+    let key = Key::load()?;
+    for solution in days.solutions(year)? {
+        let path = input_path(solution);
+        let encrypted_path = encryption::encrypted_path(&path);
+        if !Path::new(&path).exists() {
+            println!("No input to encrypt at {}", path);
+            continue;
+        }
+        let input = fs::read(&path).with_context(|| format!("Could not read {}", path))?;
+        let unchanged = Input::load_encrypted(&encrypted_path, &key)
+            .is_ok_and(|encrypted| encrypted.as_str().as_bytes() == input);
+        if unchanged {
+            println!("{} is up to date", encrypted_path.display());
+        } else {
+            fs::write(&encrypted_path, key.encrypt(&input)?)?;
+            println!("Encrypted {}", encrypted_path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Decrypt the encrypted inputs of the days, replacing any plain inputs
+fn decrypt(days: Days, year: Option<u16>) -> Result<()> {
+    let key = Key::load()?;
+    for solution in days.solutions(year)? {
+        let path = input_path(solution);
+        let encrypted_path = encryption::encrypted_path(&path);
+        if !encrypted_path.exists() {
+            println!("No input to decrypt at {}", encrypted_path.display());
+            continue;
+        }
+        let input = Input::load_encrypted(&encrypted_path, &key)
+            .with_context(|| format!("Could not decrypt {}", encrypted_path.display()))?;
+        fs::write(&path, input.as_str())?;
+        println!("Decrypted {}", path);
+    }
+    Ok(())
+}
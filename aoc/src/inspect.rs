@@ -0,0 +1,271 @@
+//! Inspection of puzzle inputs, to spot broken downloads and get a feel for an input's shape
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use util::registry::Solution;
+use util::Input;
+
+use crate::answers::{Answers, ANSWERS_PATH};
+use crate::run::input_path;
+
+/// The most rows to show in the line length histogram, longer ones are grouped into ranges
+const HISTOGRAM_ROWS: usize = 10;
+
+/// Text that means the input isn't an input, but a page AoC served instead of it
+const BROKEN_DOWNLOADS: [(&str, &str); 4] = [
+    ("<!doctype html", "an HTML page"),
+    ("<html", "an HTML page"),
+    ("please log in", "a login prompt"),
+    (
+        "before it unlocks",
+        "a request for a puzzle that hasn't unlocked yet",
+    ),
+];
+
+/// Get the hash identifying an input
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// The shape of an input
+#[derive(Debug, PartialEq, Eq)]
+pub struct Inspection {
+    pub hash: String,
+    pub lines: usize,
+    /// The number of lines of every length
+    pub line_lengths: BTreeMap<usize, usize>,
+    /// The number of characters of every class
+    pub char_classes: BTreeMap<&'static str, usize>,
+    /// The number of lines in every section separated by blank lines
+    pub sections: Vec<usize>,
+    pub trailing_newline: bool,
+    pub warnings: Vec<String>,
+}
+
+/// Inspect an input
+pub fn inspect_input(input: &str) -> Inspection {
+    let trailing_newline = input.ends_with('\n');
+    let content = input.strip_suffix('\n').unwrap_or(input);
+    let lines = if input.is_empty() {
+        Vec::new()
+    } else {
+        content.split('\n').collect_vec()
+    };
+    let line_lengths = lines.iter().map(|line| line.chars().count()).counts();
+    let char_classes = input.chars().map(char_class).counts();
+    let sections = lines
+        .split(|line| line.trim().is_empty())
+        .map(|section| section.len())
+        .filter(|&length| length > 0)
+        .collect();
+
+    let mut warnings = Vec::new();
+    if input.is_empty() {
+        warnings.push("The input is empty".to_string());
+    }
+    let lowercase = input.to_lowercase();
+    for (marker, description) in BROKEN_DOWNLOADS {
+        let warning = format!("The input looks like {}, download it again", description);
+        if lowercase.contains(marker) && !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+    if input.contains('\r') {
+        warnings.push("The input has Windows line endings".to_string());
+    }
+
+    Inspection {
+        hash: hash(input),
+        lines: lines.len(),
+        line_lengths: line_lengths.into_iter().collect(),
+        char_classes: char_classes.into_iter().collect(),
+        sections,
+        trailing_newline,
+        warnings,
+    }
+}
+
+fn char_class(c: char) -> &'static str {
+    match c {
+        '0'..='9' => "digits",
+        'a'..='z' => "lowercase letters",
+        'A'..='Z' => "uppercase letters",
+        '\n' => "newlines",
+        ' ' | '\t' => "spaces",
+        c if c.is_ascii_punctuation() => "punctuation",
+        c if c.is_control() => "control characters",
+        _ => "other characters",
+    }
+}
+
+impl Inspection {
+    /// Group the line lengths into at most [`HISTOGRAM_ROWS`] ranges of lengths
+    fn histogram(&self) -> Vec<((usize, usize), usize)> {
+        let (min, max) = match (
+            self.line_lengths.keys().next(),
+            self.line_lengths.keys().last(),
+        ) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => return Vec::new(),
+        };
+        if self.line_lengths.len() <= HISTOGRAM_ROWS {
+            return self
+                .line_lengths
+                .iter()
+                .map(|(&length, &count)| ((length, length), count))
+                .collect();
+        }
+        let width = (max - min) / HISTOGRAM_ROWS + 1;
+        self.line_lengths
+            .iter()
+            .map(|(&length, &count)| ((length - min) / width, count))
+            .into_grouping_map()
+            .sum()
+            .into_iter()
+            .sorted()
+            .map(|(bucket, count)| {
+                let start = min + bucket * width;
+                ((start, start + width - 1), count)
+            })
+            .collect()
+    }
+}
+
+impl Display for Inspection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "SHA-256: {}", self.hash)?;
+        writeln!(f, "Lines: {}", self.lines)?;
+        writeln!(
+            f,
+            "Trailing newline: {}",
+            if self.trailing_newline { "yes" } else { "no" }
+        )?;
+        writeln!(f, "Line lengths:")?;
+        let histogram = self.histogram();
+        let widest = histogram.iter().map(|(_, count)| *count).max().unwrap_or(1);
+        for ((start, end), count) in histogram {
+            let lengths = if start == end {
+                start.to_string()
+            } else {
+                format!("{}-{}", start, end)
+            };
+            let bar = "#".repeat((count * 40).div_ceil(widest));
+            writeln!(f, "  {:>9} {:>6} {}", lengths, count, bar)?;
+        }
+        writeln!(f, "Characters:")?;
+        for (class, count) in &self.char_classes {
+            writeln!(f, "  {:>9} {}", count, class)?;
+        }
+        match self.sections.len() {
+            0 => writeln!(f, "Sections separated by blank lines: none")?,
+            1..=5 => writeln!(
+                f,
+                "Sections separated by blank lines: {} ({} lines)",
+                self.sections.len(),
+                self.sections.iter().join(", ")
+            )?,
+            count => writeln!(
+                f,
+                "Sections separated by blank lines: {} ({} to {} lines)",
+                count,
+                self.sections.iter().min().unwrap_or(&0),
+                self.sections.iter().max().unwrap_or(&0)
+            )?,
+        }
+        for warning in &self.warnings {
+            writeln!(f, "Warning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
+
+/// Inspect the input of a day and compare it with the input its answers were recorded for
+pub fn inspect(solution: &Solution) -> Result<()> {
+    let path = input_path(solution);
+    let input = Input::load(&path).with_context(|| format!("Could not load {}", path))?;
+    let inspection = inspect_input(input.as_str());
+    println!(
+        "{} day {}: {} ({})",
+        solution.year, solution.day, solution.title, path
+    );
+    print!("{}", inspection);
+    let answers = Answers::load(ANSWERS_PATH)?;
+    match answers
+        .get(solution.year, solution.day)
+        .and_then(|answers| answers.input_hash.as_deref())
+    {
+        Some(recorded) if recorded == inspection.hash => {
+            println!("The answers were recorded for this input")
+        }
+        Some(_) => println!("Warning: The answers were recorded for a different input"),
+        None => println!("No input was recorded with the answers"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{hash, inspect_input};
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            hash("")
+        );
+    }
+
+    #[test]
+    fn test_inspect_input() {
+        // given the example of day 1
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        // expect its shape to be described
+        let inspection = inspect_input(input);
+        assert_eq!(14, inspection.lines);
+        assert!(inspection.trailing_newline);
+        assert_eq!(vec![3, 1, 2, 3, 1], inspection.sections);
+        assert_eq!(
+            vec![(0, 4), (4, 9), (5, 1)],
+            inspection.line_lengths.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&14), inspection.char_classes.get("newlines"));
+        assert_eq!(Some(&41), inspection.char_classes.get("digits"));
+        assert!(inspection.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_histogram() {
+        // given lines of more lengths than fit in the histogram
+        let input = (1..=30)
+            .map(|length| "#".repeat(length))
+            .collect::<Vec<_>>();
+
+        // expect the lengths to be grouped into ranges
+        let histogram = inspect_input(&input.join("\n")).histogram();
+        assert_eq!(10, histogram.len());
+        assert_eq!(((1, 3), 3), histogram[0]);
+        assert_eq!(((28, 30), 3), histogram[9]);
+    }
+
+    #[test]
+    fn test_broken_download() {
+        // given the page AoC serves when not logged in
+        let input = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+        // expect a warning
+        assert_eq!(
+            vec!["The input looks like a login prompt, download it again"],
+            inspect_input(input).warnings
+        );
+
+        // and an HTML page to be warned about once
+        assert_eq!(1, inspect_input("<!DOCTYPE html>\n<html>").warnings.len());
+    }
+}
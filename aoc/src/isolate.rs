@@ -0,0 +1,227 @@
+//! Running parts in child processes, so runaway parts can be stopped and reported
+use anyhow::{anyhow, Context, Result};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::io::{ErrorKind, Read};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use wait_timeout::ChildExt;
+
+use util::alloc;
+use util::registry::{self, Solution};
+use util::trace;
+use util::Input;
+
+use crate::report::{Outcome, PartAllocations};
+use crate::run::{implemented_parts, input_path, run_part};
+
+/// Limits on the resources a part may use
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Args)]
+pub struct Limits {
+    /// The wall-clock time a part may take, in seconds, which also limits its CPU time
+    #[arg(long = "timeout", default_value = "10", value_parser = parse_seconds)]
+    pub timeout: Duration,
+    /// The address space a part may use, in MiB
+    #[arg(long = "memory", default_value_t = 4096)]
+    pub memory: u64,
+}
+
+impl Limits {
+    /// The arguments to pass the limits on to a child process
+    fn args(&self) -> [String; 4] {
+        [
+            "--timeout".to_string(),
+            self.timeout.as_secs_f64().to_string(),
+            "--memory".to_string(),
+            self.memory.to_string(),
+        ]
+    }
+
+    /// Limit the CPU time and address space of this process
+    #[cfg(unix)]
+    fn apply(&self) -> Result<()> {
+        let cpu = self.timeout.as_secs() + 1;
+        let memory = self.memory.saturating_mul(1024 * 1024);
+        for (resource, limit) in [(libc::RLIMIT_CPU, cpu), (libc::RLIMIT_AS, memory)] {
+            let limit = libc::rlimit {
+                rlim_cur: limit as libc::rlim_t,
+                rlim_max: limit as libc::rlim_t,
+            };
+            // SAFETY: setrlimit only reads the limit, which outlives the call
+            if unsafe { libc::setrlimit(resource, &limit) } != 0 {
+                return Err(std::io::Error::last_os_error()).context("Could not set limits");
+            }
+        }
+        Ok(())
+    }
+
+    /// Resource limits aren't supported here, only the wall-clock time is limited
+    #[cfg(not(unix))]
+    fn apply(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn parse_seconds(seconds: &str) -> Result<Duration> {
+    Duration::try_from_secs_f64(seconds.parse()?).map_err(|error| anyhow!("{}", error))
+}
+
+/// The result of a part, as passed from the child process to the runner
+#[derive(Serialize, Deserialize)]
+struct ChildResult {
+    outcome: Outcome,
+    duration: Duration,
+    allocations: Option<PartAllocations>,
+}
+
+/// Run a part of a day in this process, as the child of a runner, and write the result to stdout
+pub fn run_child(year: u16, day: u8, part: u8, limits: &Limits) -> Result<()> {
+    limits.apply()?;
+    let solution =
+        registry::find(year, day).ok_or_else(|| anyhow!("No solution for {} day {}", year, day))?;
+    let (_, implementation) = implemented_parts(solution)
+        .find(|(implemented, _)| *implemented == part)
+        .ok_or_else(|| anyhow!("Part {} of {} day {} is not implemented", part, year, day))?;
+    let (input, parse) = trace::parse_span(year, day)
+        .in_scope(|| alloc::measure(|| Input::load(input_path(solution))));
+    let _span = trace::part_span(year, day, part).entered();
+    let start = Instant::now();
+    let (outcome, part) = alloc::measure(|| match &input {
+        Ok(input) => run_part(implementation, input),
+        Err(error) if error.kind() == ErrorKind::OutOfMemory => Outcome::OutOfMemory,
+        Err(error) => Outcome::Error(format!("Could not load input: {}", error)),
+    });
+    let result = ChildResult {
+        outcome,
+        duration: start.elapsed(),
+        allocations: parse
+            .zip(part)
+            .map(|(parse, part)| PartAllocations { parse, part }),
+    };
+    serde_json::to_writer(std::io::stdout().lock(), &result)?;
+    Ok(())
+}
+
+/// The outcome of a part, how long it took and the allocations it made, if they are counted
+type IsolatedResult = (Outcome, Duration, Option<PartAllocations>);
+
+/// Run a part of a day in a child process, stopping it when it runs out of time
+pub fn run_isolated(solution: &Solution, part: u8, limits: &Limits) -> Result<IsolatedResult> {
+    let start = Instant::now();
+    let mut child = Command::new(std::env::current_exe()?)
+        .args(["--year", &solution.year.to_string(), "part"])
+        .args([solution.day.to_string(), part.to_string()])
+        .args(limits.args())
+        .args(crate::trace::child_args())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+    let status = match child.wait_timeout(limits.timeout)? {
+        Some(status) => Some(status),
+        None => {
+            child.kill()?;
+            child.wait()?;
+            None
+        }
+    };
+    let (stdout, stderr) = (join(stdout)?, join(stderr)?);
+    if crate::trace::to_stderr() {
+        eprint!("{}", stderr);
+    }
+    let status = match status {
+        Some(status) => status,
+        None => return Ok((Outcome::Timeout, start.elapsed(), None)),
+    };
+    if status.success() {
+        let result: ChildResult = serde_json::from_str(&stdout)
+            .with_context(|| format!("Invalid result from part: {}", stdout))?;
+        Ok((result.outcome, result.duration, result.allocations))
+    } else {
+        Ok((failure(status, &stderr), start.elapsed(), None))
+    }
+}
+
+/// Read a pipe to the end in a thread, so a child can't block on writing to a full pipe
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_string(&mut output);
+        }
+        output
+    })
+}
+
+fn join(reader: JoinHandle<String>) -> Result<String> {
+    reader
+        .join()
+        .map_err(|_| anyhow!("Could not read the output of a part"))
+}
+
+/// Work out why a child process failed, from how it exited and what it wrote to stderr
+fn failure(status: ExitStatus, stderr: &str) -> Outcome {
+    if stderr.contains("memory allocation of") {
+        return Outcome::OutOfMemory;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if status.signal() == Some(libc::SIGXCPU) {
+            return Outcome::Timeout;
+        }
+    }
+    match stderr.lines().last() {
+        Some(message) => Outcome::Error(format!("Crashed with {}: {}", status, message)),
+        None => Outcome::Error(format!("Crashed with {}", status)),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod test {
+    use super::{failure, parse_seconds, Limits};
+    use crate::report::Outcome;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(Duration::from_millis(1500), parse_seconds("1.5").unwrap());
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("soon").is_err());
+    }
+
+    #[test]
+    fn test_args() {
+        // expect the limits to survive being passed to a child
+        let limits = Limits {
+            timeout: Duration::from_millis(2500),
+            memory: 512,
+        };
+        assert_eq!(["--timeout", "2.5", "--memory", "512"], limits.args());
+    }
+
+    #[test]
+    fn test_failure() {
+        // given a child that aborted after failing to allocate
+        let aborted = ExitStatus::from_raw(libc::SIGABRT);
+        let stderr = "memory allocation of 4294967296 bytes failed\n";
+
+        // expect it to have run out of memory
+        assert_eq!(Outcome::OutOfMemory, failure(aborted, stderr));
+
+        // and a child stopped for using too much CPU time to have timed out
+        let stopped = ExitStatus::from_raw(libc::SIGXCPU);
+        assert_eq!(Outcome::Timeout, failure(stopped, ""));
+
+        // and other failures to be reported with the last thing the child said
+        let crashed = failure(aborted, "thread 'main' has overflowed its stack\n");
+        assert!(
+            matches!(crashed, Outcome::Error(error) if error.ends_with("overflowed its stack"))
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use itertools::Itertools;
+
+use util::registry::{self, Part};
+
+/// Render the calendar of a year, showing which days and parts have been implemented
+pub fn calendar(year: u16) -> String {
+    let mut calendar = format!("{}  Part 1  Part 2  Title\n", year);
+    for day in 1..=25 {
+        calendar += &match registry::find(year, day) {
+            Some(solution) => format!(
+                "{:>4}  {:<6}  {:<6}  {}\n",
+                day,
+                implemented(solution.part1),
+                implemented(solution.part2),
+                solution.title
+            ),
+            None => format!("{:>4}  {:<6}  {:<6}  (missing)\n", day, "-", "-"),
+        };
+    }
+    calendar
+}
+
+fn implemented(part: Option<Part>) -> &'static str {
+    if part.is_some() {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// Print the calendar of a year, or of every year with registered solutions
+pub fn list(year: Option<u16>) {
+    let years = match year {
+        Some(year) => vec![year],
+        None => registry::years(),
+    };
+    println!("{}", years.into_iter().map(calendar).join("\n"));
+}
+
+#[cfg(test)]
+mod test {
+    use super::calendar;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_calendar() {
+        // given the calendar for 2022
+        let calendar = calendar(2022);
+        let lines = calendar.lines().collect_vec();
+
+        // expect a header and a line for every day, including the missing ones
+        assert_eq!(26, lines.len());
+        assert_eq!("   1  yes     yes     Calorie Counting", lines[1]);
+        assert_eq!("  25  -       -       (missing)", lines[25]);
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
+use std::io::Write;
+
+mod answers;
+mod generate;
+mod inputs;
+mod inspect;
+mod isolate;
+mod list;
+mod readme;
+mod repl;
+mod report;
+mod run;
+mod trace;
+#[cfg(target_os = "linux")]
+mod watch;
+
+// Link all the days the runner depends on, registering their solutions
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Runner for the Advent of Code solutions
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// The year of the calendar, defaults to the latest year with solutions
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    #[command(flatten)]
+    tracing: trace::Tracing,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a random puzzle input for a day and print it to stdout
+    Gen {
+        /// The day to generate input for
+        day: u8,
+        /// The size of the generated input, roughly the number of records in it
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        /// Seed for the random generator, a random seed is picked if none is given
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Encrypt or decrypt the puzzle inputs
+    Inputs {
+        #[command(subcommand)]
+        command: inputs::Command,
+    },
+    /// Describe the shape of the input of a day and warn about broken downloads
+    Inspect {
+        /// The day to inspect
+        day: u8,
+    },
+    /// List the calendar, showing which days and parts have been implemented
+    List,
+    /// Run the solutions on their puzzle inputs and print the answers
+    Run {
+        /// The day to run, or "all" to run every implemented day of the year, or of all years if
+        /// no year is given
+        #[arg(default_value = "all")]
+        days: run::Days,
+        /// The format to report the results in
+        #[arg(long, value_enum, default_value_t = report::Format::Text)]
+        format: report::Format,
+        #[command(flatten)]
+        limits: isolate::Limits,
+    },
+    /// Run the solutions and record their answers as solved, earning stars for them
+    Record {
+        /// The day to record, or "all" to record every implemented day
+        #[arg(default_value = "all")]
+        days: run::Days,
+        #[command(flatten)]
+        limits: isolate::Limits,
+    },
+    /// Rewrite the progress section of the README with stars and runtimes of all days
+    Readme {
+        /// The number of times to run each day, the median runtime is shown
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+        #[command(flatten)]
+        limits: isolate::Limits,
+    },
+    /// Run a single part in this process, used by the runner to run every part in a child process
+    #[command(hide = true)]
+    Part {
+        day: u8,
+        part: u8,
+        #[command(flatten)]
+        limits: isolate::Limits,
+    },
+    /// Run a day on snippets pasted into the terminal, which can be saved as example fixtures
+    Repl {
+        /// The day to run
+        day: u8,
+    },
+    /// Rebuild and re-run a day whenever its sources, util, its input or its fixtures change
+    #[cfg(target_os = "linux")]
+    Watch {
+        /// The day to watch
+        day: u8,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    cli.tracing
+        .init(matches!(cli.command, Command::Part { .. }))?;
+    match cli.command {
+        Command::Gen { day, size, seed } => {
+            let year = cli
+                .year
+                .or_else(run::latest_year)
+                .ok_or_else(|| anyhow!("No solutions registered"))?;
+            let seed = seed.unwrap_or_else(rand::random);
+            eprintln!(
+                "Generating input for {} day {} with seed {}",
+                year, day, seed
+            );
+            let input = generate::generate(year, day, size, seed)?;
+            std::io::stdout().lock().write_all(input.as_bytes())?;
+        }
+        Command::Inputs { command } => inputs::inputs(command, cli.year)?,
+        Command::Inspect { day } => inspect::inspect(run::Days::Day(day).solutions(cli.year)?[0])?,
+        Command::List => list::list(cli.year),
+        Command::Run {
+            days,
+            format,
+            limits,
+        } => run::run(days, cli.year, format, &limits)?,
+        Command::Record { days, limits } => answers::record(days, cli.year, &limits)?,
+        Command::Readme { runs, limits } => readme::readme(runs, &limits)?,
+        Command::Part { day, part, limits } => {
+            let year = cli.year.ok_or_else(|| anyhow!("No year given"))?;
+            isolate::run_child(year, day, part, &limits)?
+        }
+        Command::Repl { day } => repl::repl(run::Days::Day(day).solutions(cli.year)?[0])?,
+        #[cfg(target_os = "linux")]
+        Command::Watch { day } => watch::watch(run::Days::Day(day).solutions(cli.year)?[0])?,
+    }
+    Ok(())
+}
@@ -0,0 +1,182 @@
+//! Generation of the progress table in the README
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::fs;
+use std::time::Duration;
+
+use util::registry;
+
+use crate::answers::{Answers, ANSWERS_PATH};
+use crate::isolate::Limits;
+use crate::report::Outcome;
+use crate::run::run_solution;
+
+pub const README_PATH: &str = "README.md";
+
+/// Markers around the generated section of the README
+const SECTION_START: &str = "<!-- progress start -->";
+const SECTION_END: &str = "<!-- progress end -->";
+
+/// A day in the progress table
+pub struct Progress {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub stars: usize,
+    /// Median runtimes of the parts, if they have been implemented and succeed
+    pub runtimes: [Option<Duration>; 2],
+}
+
+/// Render the progress of all days as a table per year
+pub fn table(progress: &[Progress]) -> String {
+    progress
+        .iter()
+        .group_by(|progress| progress.year)
+        .into_iter()
+        .map(|(year, days)| {
+            let mut table = format!(
+                "### {}\n\n\
+                 | Day | Title | Stars | Part 1 | Part 2 |\n\
+                 |----:|-------|-------|-------:|-------:|\n",
+                year
+            );
+            for progress in days {
+                table += &format!(
+                    "| {} | [{}]({}/day{:02}) | {} | {} | {} |\n",
+                    progress.day,
+                    progress.title,
+                    progress.year,
+                    progress.day,
+                    "⭐".repeat(progress.stars),
+                    format_runtime(progress.runtimes[0]),
+                    format_runtime(progress.runtimes[1]),
+                );
+            }
+            table
+        })
+        .join("\n")
+}
+
+fn format_runtime(runtime: Option<Duration>) -> String {
+    match runtime {
+        Some(runtime) => format!("{:.3}ms", runtime.as_secs_f64() * 1000.0),
+        None => "-".to_string(),
+    }
+}
+
+/// Replace the generated section of the README
+pub fn replace_section(readme: &str, section: &str) -> Result<String> {
+    let (before, rest) = readme
+        .split_once(SECTION_START)
+        .ok_or_else(|| anyhow!("No {} marker in the README", SECTION_START))?;
+    let (_, after) = rest
+        .split_once(SECTION_END)
+        .ok_or_else(|| anyhow!("No {} marker in the README", SECTION_END))?;
+    Ok(format!(
+        "{}{}\n{}{}{}",
+        before, SECTION_START, section, SECTION_END, after
+    ))
+}
+
+/// Get the median of some durations
+fn median(mut durations: Vec<Duration>) -> Option<Duration> {
+    durations.sort();
+    durations.get(durations.len() / 2).cloned()
+}
+
+/// Run every day `runs` times and rewrite the progress section of the README
+pub fn readme(runs: usize, limits: &Limits) -> Result<()> {
+    let answers = Answers::load(ANSWERS_PATH)?;
+    let progress = registry::solutions()
+        .into_iter()
+        .map(|solution| {
+            let results = (0..runs)
+                .flat_map(|_| run_solution(solution, limits))
+                .collect_vec();
+            let runtimes = [1, 2].map(|part| {
+                let durations = results
+                    .iter()
+                    .filter(|result| result.part == part)
+                    .map(|result| match result.outcome {
+                        Outcome::Answer(_) => Some(result.duration),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                median(durations)
+            });
+            Progress {
+                year: solution.year,
+                day: solution.day,
+                title: solution.title,
+                stars: answers.stars(solution.year, solution.day),
+                runtimes,
+            }
+        })
+        .collect_vec();
+    let readme = fs::read_to_string(README_PATH)?;
+    fs::write(README_PATH, replace_section(&readme, &table(&progress))?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{median, replace_section, table, Progress};
+    use std::time::Duration;
+
+    #[test]
+    fn test_table() {
+        // given the progress of two days
+        let progress = [
+            Progress {
+                year: 2022,
+                day: 1,
+                title: "Calorie Counting",
+                stars: 2,
+                runtimes: [
+                    Some(Duration::from_micros(700)),
+                    Some(Duration::from_micros(850)),
+                ],
+            },
+            Progress {
+                year: 2022,
+                day: 2,
+                title: "Rock Paper Scissors",
+                stars: 1,
+                runtimes: [Some(Duration::from_micros(1200)), None],
+            },
+        ];
+
+        // expect them to be rendered as a table
+        assert_eq!(
+            table(&progress),
+            "### 2022\n\
+             \n\
+             | Day | Title | Stars | Part 1 | Part 2 |\n\
+             |----:|-------|-------|-------:|-------:|\n\
+             | 1 | [Calorie Counting](2022/day01) | ⭐⭐ | 0.700ms | 0.850ms |\n\
+             | 2 | [Rock Paper Scissors](2022/day02) | ⭐ | 1.200ms | - |\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        // given a README with a generated section
+        let readme = "# Title\n<!-- progress start -->\nold\n<!-- progress end -->\nrest\n";
+
+        // expect only the section to be replaced
+        assert_eq!(
+            replace_section(readme, "new\n").unwrap(),
+            "# Title\n<!-- progress start -->\nnew\n<!-- progress end -->\nrest\n"
+        );
+
+        // and a README without markers to be rejected
+        assert!(replace_section("# Title\n", "new\n").is_err());
+    }
+
+    #[test]
+    fn test_median() {
+        let durations = [3, 1, 2].map(Duration::from_millis).to_vec();
+        assert_eq!(Some(Duration::from_millis(2)), median(durations));
+        assert_eq!(None, median(vec![]));
+    }
+}
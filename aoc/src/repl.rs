@@ -0,0 +1,244 @@
+//! Interactive scratch mode, running a day on snippets pasted into the terminal
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use util::fixtures;
+use util::registry::Solution;
+use util::Input;
+
+use crate::report::{Format, Outcome, Reporter};
+use crate::run::run_input;
+
+/// A line on its own that ends a snippet
+const SENTINEL: &str = ".";
+
+const HELP: &str = "\
+Paste some input and end it with a line containing only '.' to run both parts on it.
+Lines starting with ':' are commands when no snippet is being entered:
+  :history               Show the snippets entered so far
+  :promote [N] [NAME]    Save snippet N, or the last one, and its answers as an example the
+                         day's tests check
+  :help                  Show this help
+  :quit                  Leave, as does end of file";
+
+/// Get the directory the example fixtures of a day are kept in
+pub fn fixtures_path(solution: &Solution) -> PathBuf {
+    PathBuf::from(format!("{}/day{:02}", solution.year, solution.day)).join(fixtures::DIRECTORY)
+}
+
+/// A snippet that has been run, with the answers to the parts that succeeded
+struct Snippet {
+    lines: Vec<String>,
+    answers: [Option<String>; 2],
+}
+
+/// A scratch session for a day
+pub struct Repl {
+    solution: &'static Solution,
+    fixtures: PathBuf,
+    history: Vec<Snippet>,
+}
+
+impl Repl {
+    pub fn new(solution: &'static Solution, fixtures: impl Into<PathBuf>) -> Repl {
+        Repl {
+            solution,
+            fixtures: fixtures.into(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Read snippets and commands until the end of the input or until asked to quit
+    pub fn run(&mut self, input: impl BufRead, mut out: impl Write) -> Result<()> {
+        writeln!(
+            out,
+            "{} day {}: {}\n{}",
+            self.solution.year, self.solution.day, self.solution.title, HELP
+        )?;
+        let mut snippet = Vec::new();
+        for line in input.lines() {
+            let line = line?;
+            if snippet.is_empty() && line.starts_with(':') {
+                match self.command(&line, &mut out) {
+                    Ok(true) => continue,
+                    Ok(false) => return Ok(()),
+                    Err(error) => writeln!(out, "Error: {:#}", error)?,
+                }
+            } else if line == SENTINEL {
+                self.evaluate(std::mem::take(&mut snippet), &mut out)?;
+            } else {
+                snippet.push(line);
+            }
+        }
+        if !snippet.is_empty() {
+            self.evaluate(snippet, &mut out)?;
+        }
+        Ok(())
+    }
+
+    /// Run both parts on a snippet and keep it in the history, along with its answers
+    fn evaluate(&mut self, lines: Vec<String>, out: &mut impl Write) -> Result<()> {
+        let mut reporter = Reporter::new(Format::Text, &mut *out);
+        let mut answers = [None, None];
+        for result in run_input(self.solution, &Input::from_lines(&lines)) {
+            if let Outcome::Answer(answer) = &result.outcome {
+                answers[usize::from(result.part - 1)] = Some(answer.clone());
+            }
+            reporter.report(result)?;
+        }
+        reporter.finish()?;
+        self.history.push(Snippet { lines, answers });
+        writeln!(out, "Saved as snippet {}", self.history.len())?;
+        Ok(())
+    }
+
+    /// Handle a command, returning whether to carry on
+    fn command(&mut self, line: &str, out: &mut impl Write) -> Result<bool> {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some(":quit") => return Ok(false),
+            Some(":help") => writeln!(out, "{}", HELP)?,
+            Some(":history") => {
+                for (index, snippet) in self.history.iter().enumerate() {
+                    writeln!(out, "Snippet {}:", index + 1)?;
+                    for line in &snippet.lines {
+                        writeln!(out, "  {}", line)?;
+                    }
+                }
+            }
+            Some(":promote") => {
+                let (index, name) = match words.next() {
+                    Some(word) => match word.parse::<usize>() {
+                        Ok(index) => (index, words.next()),
+                        Err(_) => (self.history.len(), Some(word)),
+                    },
+                    None => (self.history.len(), None),
+                };
+                let path = self.promote(index, name)?;
+                writeln!(out, "Saved snippet {} as {}", index, path.display())?;
+            }
+            _ => bail!("Unknown command {}, try :help", line),
+        }
+        Ok(true)
+    }
+
+    /// Save a snippet from the history as an example fixture, named `exampleN` unless a name is
+    /// given, with the answers it got next to it
+    fn promote(&self, index: usize, name: Option<&str>) -> Result<PathBuf> {
+        let snippet = index
+            .checked_sub(1)
+            .and_then(|index| self.history.get(index))
+            .ok_or_else(|| anyhow!("No snippet {}", index))?;
+        fs::create_dir_all(&self.fixtures)
+            .with_context(|| format!("Could not create {}", self.fixtures.display()))?;
+        let path = match name {
+            Some(name) if name.ends_with(".part1") || name.ends_with(".part2") => {
+                bail!("{} is the name of an answer file", name)
+            }
+            Some(name) => self.fixtures.join(name),
+            None => (1..)
+                .map(|number| self.fixtures.join(format!("example{}", number)))
+                .find(|path| !path.exists())
+                .expect("There is always a free name"),
+        };
+        if path.exists() {
+            bail!("{} already exists", path.display());
+        }
+        fs::write(
+            &path,
+            Input::from_lines(&snippet.lines).as_str().to_string() + "\n",
+        )?;
+        for (part, answer) in (1..).zip(&snippet.answers) {
+            if let Some(answer) = answer {
+                fs::write(fixtures::answer_path(&path, part), answer)?;
+            }
+        }
+        Ok(path)
+    }
+}
+
+/// Start a scratch session for a day on the terminal
+pub fn repl(solution: &'static Solution) -> Result<()> {
+    if !Path::new("util").is_dir() {
+        bail!("The scratch mode has to be started in the root of the workspace");
+    }
+    Repl::new(solution, fixtures_path(solution)).run(std::io::stdin().lock(), std::io::stdout())
+}
+
+#[cfg(test)]
+mod test {
+    use super::Repl;
+    use crate::run::Days;
+    use std::fs;
+    use std::io::Cursor;
+    use util::fixtures;
+
+    #[test]
+    fn test_repl() {
+        // given a session entering two snippets for day 1, the last one ended by end of file
+        let fixtures = std::env::temp_dir().join(format!("aoc-repl-{}", std::process::id()));
+        let solution = Days::Day(1).solutions(Some(2022)).unwrap()[0];
+        let session = "1000\n2000\n.\n:history\n1000\n\n5000\n";
+
+        // when running it
+        let mut out = Vec::new();
+        Repl::new(solution, &fixtures)
+            .run(Cursor::new(session), &mut out)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        // expect both snippets to be run and the history to be shown
+        assert!(out.contains("Part 1 ("), "{}", out);
+        assert!(out.contains("\n3000\n"), "{}", out);
+        assert!(out.contains("Snippet 1:\n  1000\n  2000\n"), "{}", out);
+        assert!(out.contains("\n5000\n"), "{}", out);
+
+        // and nothing to be saved as a fixture without asking for it
+        assert!(!fixtures.exists());
+    }
+
+    #[test]
+    fn test_promote() {
+        // given a session with a snippet that is promoted twice
+        let fixtures = std::env::temp_dir().join(format!("aoc-promote-{}", std::process::id()));
+        let solution = Days::Day(1).solutions(Some(2022)).unwrap()[0];
+        let session = "1000\n\n2000\n.\n:promote\n:promote 1 larger\n:promote 2\n\
+                       :promote 1 larger.part1\n:quit\n3000\n";
+
+        // when running it
+        let mut out = Vec::new();
+        Repl::new(solution, &fixtures)
+            .run(Cursor::new(session), &mut out)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        // expect the snippet to be saved under a generated and a given name
+        assert_eq!(
+            "1000\n\n2000\n",
+            fs::read_to_string(fixtures.join("example1")).unwrap()
+        );
+        assert_eq!(
+            "1000\n\n2000\n",
+            fs::read_to_string(fixtures.join("larger")).unwrap()
+        );
+
+        // with the answers it got, which the tests of the day check
+        let answers = ["part1", "part2"]
+            .map(|part| fs::read_to_string(fixtures.join(format!("example1.{}", part))).ok());
+        assert_eq!(
+            [Some("2000".to_string()), Some("3000".to_string())],
+            answers
+        );
+        let checked = fixtures::check(&fixtures, aoc2022_day01::part1, aoc2022_day01::part2);
+        assert!(checked.is_ok(), "{:?}", checked);
+
+        // and missing snippets and names of answer files to be reported, and nothing to be run
+        // after quitting
+        assert!(out.contains("Error: No snippet 2"), "{}", out);
+        assert!(out.contains("Error: larger.part1 is the name"), "{}", out);
+        assert!(!out.contains("Snippet 2"), "{}", out);
+        fs::remove_dir_all(fixtures).unwrap();
+    }
+}
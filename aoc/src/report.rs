@@ -0,0 +1,447 @@
+//! Reporting of the results of running solutions, for humans or for other tools
+use clap::ValueEnum;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+use std::time::Duration;
+
+use util::alloc::Allocations;
+
+/// The format to report results in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable text
+    Text,
+    /// One JSON object per line and part
+    Json,
+    /// JUnit XML, with a test suite per day and a test case per part
+    Junit,
+    /// Test Anything Protocol
+    Tap,
+}
+
+/// The outcome of running a part
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    /// The part ran out of wall-clock or CPU time
+    Timeout,
+    /// The part ran out of address space
+    OutOfMemory,
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Answer(_) => "ok",
+            Outcome::Error(_) => "error",
+            Outcome::Timeout => "timeout",
+            Outcome::OutOfMemory => "out_of_memory",
+        }
+    }
+
+    /// Describe why the part failed, if it did
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            Outcome::Answer(_) => None,
+            Outcome::Error(error) => Some(error),
+            Outcome::Timeout => Some("timeout"),
+            Outcome::OutOfMemory => Some("out of memory"),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, Outcome::Answer(_))
+    }
+}
+
+/// The result of running a part of a day
+#[derive(Clone, Debug)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub duration: Duration,
+    /// The allocations made, if they are counted
+    pub allocations: Option<PartAllocations>,
+}
+
+/// The allocations made while loading the input and while running a part on it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAllocations {
+    pub parse: Allocations,
+    pub part: Allocations,
+}
+
+impl PartResult {
+    /// The name of the day, the same as the day's crate
+    fn day_name(&self) -> String {
+        format!("aoc{}-day{:02}", self.year, self.day)
+    }
+}
+
+/// A part result as a JSON object
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    year: u16,
+    day: u8,
+    title: &'a str,
+    part: u8,
+    status: &'a str,
+    answer: Option<&'a str>,
+    error: Option<&'a str>,
+    duration_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<&'a PartAllocations>,
+}
+
+impl<'a> From<&'a PartResult> for JsonResult<'a> {
+    fn from(result: &'a PartResult) -> JsonResult<'a> {
+        let answer = match &result.outcome {
+            Outcome::Answer(answer) => Some(answer.as_str()),
+            _ => None,
+        };
+        JsonResult {
+            year: result.year,
+            day: result.day,
+            title: result.title,
+            part: result.part,
+            status: result.outcome.status(),
+            answer,
+            error: result.outcome.error(),
+            duration_ms: result.duration.as_secs_f64() * 1000.0,
+            allocations: result.allocations.as_ref(),
+        }
+    }
+}
+
+/// Writes part results in a format as they come in.
+///
+/// Formats that can't be streamed are written when the reporter is finished.
+pub struct Reporter<W: Write> {
+    format: Format,
+    out: W,
+    results: Vec<PartResult>,
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(format: Format, out: W) -> Reporter<W> {
+        Reporter {
+            format,
+            out,
+            results: Vec::new(),
+        }
+    }
+
+    /// Report the result of a part
+    pub fn report(&mut self, result: PartResult) -> io::Result<()> {
+        match self.format {
+            Format::Text => self.write_text(&result)?,
+            Format::Json => {
+                serde_json::to_writer(&mut self.out, &JsonResult::from(&result))?;
+                writeln!(self.out)?;
+            }
+            Format::Tap => self.write_tap(&result)?,
+            Format::Junit => {}
+        }
+        self.results.push(result);
+        Ok(())
+    }
+
+    /// Finish the report and return all reported results
+    pub fn finish(mut self) -> io::Result<Vec<PartResult>> {
+        match self.format {
+            Format::Tap => {
+                if self.results.is_empty() {
+                    writeln!(self.out, "TAP version 13")?;
+                }
+                writeln!(self.out, "1..{}", self.results.len())?;
+            }
+            Format::Junit => self.write_junit()?,
+            Format::Text | Format::Json => {}
+        }
+        self.out.flush()?;
+        Ok(self.results)
+    }
+
+    fn write_text(&mut self, result: &PartResult) -> io::Result<()> {
+        let new_day = self
+            .results
+            .last()
+            .is_none_or(|last| (last.year, last.day) != (result.year, result.day));
+        if new_day {
+            writeln!(
+                self.out,
+                "{} day {}: {}",
+                result.year, result.day, result.title
+            )?;
+            if let Some(allocations) = &result.allocations {
+                writeln!(self.out, "Parse: {}", allocations.parse)?;
+            }
+        }
+        let duration = format!("{:.3}ms", result.duration.as_secs_f64() * 1000.0);
+        match &result.allocations {
+            Some(allocations) => writeln!(
+                self.out,
+                "Part {} ({}, {}):",
+                result.part, duration, allocations.part
+            )?,
+            None => writeln!(self.out, "Part {} ({}):", result.part, duration)?,
+        }
+        match &result.outcome {
+            Outcome::Answer(answer) => writeln!(self.out, "{}", answer),
+            Outcome::Error(error) => writeln!(self.out, "Error: {}", error),
+            Outcome::Timeout => writeln!(self.out, "Timeout"),
+            Outcome::OutOfMemory => writeln!(self.out, "Out of memory"),
+        }
+    }
+
+    fn write_tap(&mut self, result: &PartResult) -> io::Result<()> {
+        if self.results.is_empty() {
+            writeln!(self.out, "TAP version 13")?;
+        }
+        let status = if result.outcome.is_success() {
+            "ok"
+        } else {
+            "not ok"
+        };
+        writeln!(
+            self.out,
+            "{} {} - {} part {}",
+            status,
+            self.results.len() + 1,
+            result.day_name(),
+            result.part
+        )?;
+        // JSON strings are valid YAML, which takes care of escaping multi-line answers
+        let (key, value) = match (&result.outcome, result.outcome.error()) {
+            (Outcome::Answer(answer), _) => ("answer", answer.as_str()),
+            (_, error) => ("message", error.unwrap_or_default()),
+        };
+        writeln!(self.out, "  ---")?;
+        writeln!(self.out, "  {}: {}", key, serde_json::to_string(value)?)?;
+        writeln!(
+            self.out,
+            "  duration_ms: {:.3}",
+            result.duration.as_secs_f64() * 1000.0
+        )?;
+        if let Some(allocations) = &result.allocations {
+            writeln!(
+                self.out,
+                "  allocations: {}",
+                serde_json::to_string(allocations)?
+            )?;
+        }
+        writeln!(self.out, "  ...")
+    }
+
+    fn write_junit(&mut self) -> io::Result<()> {
+        let errors = self
+            .results
+            .iter()
+            .filter(|result| !result.outcome.is_success())
+            .count();
+        writeln!(self.out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            self.out,
+            r#"<testsuites tests="{}" failures="0" errors="{}" time="{:.6}">"#,
+            self.results.len(),
+            errors,
+            total_seconds(&self.results)
+        )?;
+        for (name, results) in &self.results.iter().group_by(|result| result.day_name()) {
+            let results = results.collect_vec();
+            writeln!(
+                self.out,
+                r#"  <testsuite name="{}" tests="{}" failures="0" errors="{}" time="{:.6}">"#,
+                name,
+                results.len(),
+                results
+                    .iter()
+                    .filter(|result| !result.outcome.is_success())
+                    .count(),
+                total_seconds(results.iter().cloned())
+            )?;
+            for result in results {
+                writeln!(
+                    self.out,
+                    r#"    <testcase classname="{}" name="part {}" time="{:.6}">"#,
+                    name,
+                    result.part,
+                    result.duration.as_secs_f64()
+                )?;
+                match (&result.outcome, result.outcome.error()) {
+                    (Outcome::Answer(answer), _) => writeln!(
+                        self.out,
+                        "      <system-out>{}</system-out>",
+                        escape_xml(answer)
+                    )?,
+                    (_, error) => writeln!(
+                        self.out,
+                        r#"      <error message="{}"/>"#,
+                        escape_xml(error.unwrap_or_default())
+                    )?,
+                }
+                writeln!(self.out, "    </testcase>")?;
+            }
+            writeln!(self.out, "  </testsuite>")?;
+        }
+        writeln!(self.out, "</testsuites>")
+    }
+}
+
+/// Sum up the time taken by a number of parts, in seconds
+fn total_seconds<'a>(results: impl IntoIterator<Item = &'a PartResult>) -> f64 {
+    results
+        .into_iter()
+        .map(|result| result.duration.as_secs_f64())
+        .sum()
+}
+
+/// Escape text for use in XML content and attributes
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&apos;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Format, Outcome, PartAllocations, PartResult, Reporter};
+    use std::time::Duration;
+    use util::alloc::Allocations;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                year: 2022,
+                day: 1,
+                title: "Calorie Counting",
+                part: 1,
+                outcome: Outcome::Answer("24000".to_string()),
+                duration: Duration::from_micros(1500),
+                allocations: None,
+            },
+            PartResult {
+                year: 2022,
+                day: 1,
+                title: "Calorie Counting",
+                part: 2,
+                outcome: Outcome::Error("No <calories> in input".to_string()),
+                duration: Duration::from_micros(500),
+                allocations: None,
+            },
+        ]
+    }
+
+    fn report(format: Format) -> String {
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(format, &mut out);
+        for result in results() {
+            reporter.report(result).unwrap();
+        }
+        reporter.finish().unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            report(Format::Text),
+            "2022 day 1: Calorie Counting\n\
+             Part 1 (1.500ms):\n\
+             24000\n\
+             Part 2 (0.500ms):\n\
+             Error: No <calories> in input\n"
+        );
+    }
+
+    #[test]
+    fn test_text_allocations() {
+        // given a result with counted allocations
+        let mut results = results();
+        results[0].allocations = Some(PartAllocations {
+            parse: Allocations {
+                count: 2,
+                bytes: 4096,
+                peak: 4096,
+            },
+            part: Allocations {
+                count: 10,
+                bytes: 640,
+                peak: 128,
+            },
+        });
+
+        // expect the allocations to be shown with the input and the runtime
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(Format::Text, &mut out);
+        reporter.report(results[0].clone()).unwrap();
+        reporter.finish().unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "2022 day 1: Calorie Counting\n\
+             Parse: 2 allocations, 4096 bytes, peak 4096 bytes\n\
+             Part 1 (1.500ms, 10 allocations, 640 bytes, peak 128 bytes):\n\
+             24000\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            report(Format::Json),
+            "{\"year\":2022,\"day\":1,\"title\":\"Calorie Counting\",\"part\":1,\"status\":\"ok\",\
+             \"answer\":\"24000\",\"error\":null,\"duration_ms\":1.5}\n\
+             {\"year\":2022,\"day\":1,\"title\":\"Calorie Counting\",\"part\":2,\"status\":\"error\",\
+             \"answer\":null,\"error\":\"No <calories> in input\",\"duration_ms\":0.5}\n"
+        );
+    }
+
+    #[test]
+    fn test_tap() {
+        assert_eq!(
+            report(Format::Tap),
+            "TAP version 13\n\
+             ok 1 - aoc2022-day01 part 1\n  \
+             ---\n  \
+             answer: \"24000\"\n  \
+             duration_ms: 1.500\n  \
+             ...\n\
+             not ok 2 - aoc2022-day01 part 2\n  \
+             ---\n  \
+             message: \"No <calories> in input\"\n  \
+             duration_ms: 0.500\n  \
+             ...\n\
+             1..2\n"
+        );
+    }
+
+    #[test]
+    fn test_junit() {
+        assert_eq!(
+            report(Format::Junit),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites tests=\"2\" failures=\"0\" errors=\"1\" time=\"0.002000\">\n  \
+             <testsuite name=\"aoc2022-day01\" tests=\"2\" failures=\"0\" errors=\"1\" time=\"0.002000\">\n    \
+             <testcase classname=\"aoc2022-day01\" name=\"part 1\" time=\"0.001500\">\n      \
+             <system-out>24000</system-out>\n    \
+             </testcase>\n    \
+             <testcase classname=\"aoc2022-day01\" name=\"part 2\" time=\"0.000500\">\n      \
+             <error message=\"No &lt;calories&gt; in input\"/>\n    \
+             </testcase>\n  \
+             </testsuite>\n\
+             </testsuites>\n"
+        );
+    }
+}
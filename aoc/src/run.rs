@@ -0,0 +1,193 @@
+use anyhow::{anyhow, bail, Result};
+use std::any::Any;
+use std::panic;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use util::registry::{self, Part, Solution};
+use util::trace;
+use util::Input;
+
+use crate::isolate::{self, Limits};
+use crate::report::{Format, Outcome, PartResult, Reporter};
+
+/// The days to run
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Days {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Days {
+    type Err = anyhow::Error;
+
+    fn from_str(days: &str) -> Result<Days> {
+        match days {
+            "all" => Ok(Days::All),
+            day => Ok(Days::Day(u8::from_str(day)?)),
+        }
+    }
+}
+
+impl Days {
+    /// Get the registered solutions for the days.
+    ///
+    /// All days means all days of every year unless a year is given, while a single day is
+    /// looked up in the latest year unless a year is given.
+    pub fn solutions(self, year: Option<u16>) -> Result<Vec<&'static Solution>> {
+        match self {
+            Days::All => Ok(registry::solutions()
+                .into_iter()
+                .filter(|solution| year.is_none_or(|year| solution.year == year))
+                .collect()),
+            Days::Day(day) => {
+                let year = year
+                    .or_else(latest_year)
+                    .ok_or_else(|| anyhow!("No solutions registered"))?;
+                registry::find(year, day)
+                    .map(|solution| vec![solution])
+                    .ok_or_else(|| anyhow!("No solution for {} day {}", year, day))
+            }
+        }
+    }
+}
+
+/// Get the latest year with registered solutions
+pub fn latest_year() -> Option<u16> {
+    registry::years().last().cloned()
+}
+
+/// Get the path to the input of a day
+pub fn input_path(solution: &Solution) -> String {
+    format!("{}/day{:02}/input", solution.year, solution.day)
+}
+
+/// Run the implemented parts of a day on its input, each in a child process within some limits
+pub fn run_solution(solution: &'static Solution, limits: &Limits) -> Vec<PartResult> {
+    implemented_parts(solution)
+        .map(|(part, _)| {
+            let (outcome, duration, allocations) = isolate::run_isolated(solution, part, limits)
+                .unwrap_or_else(|error| {
+                    let error = format!("Could not run part: {:#}", error);
+                    (Outcome::Error(error), Duration::ZERO, None)
+                });
+            PartResult {
+                year: solution.year,
+                day: solution.day,
+                title: solution.title,
+                part,
+                outcome,
+                duration,
+                allocations,
+            }
+        })
+        .collect()
+}
+
+/// Run the implemented parts of a day on any input, in this process
+pub fn run_input(solution: &'static Solution, input: &Input) -> Vec<PartResult> {
+    implemented_parts(solution)
+        .map(|(part, implementation)| {
+            let _span = trace::part_span(solution.year, solution.day, part).entered();
+            let start = Instant::now();
+            let outcome = run_part(implementation, input);
+            PartResult {
+                year: solution.year,
+                day: solution.day,
+                title: solution.title,
+                part,
+                outcome,
+                duration: start.elapsed(),
+                allocations: None,
+            }
+        })
+        .collect()
+}
+
+/// Run a part on an input.
+///
+/// A part that panics is reported as an error instead of taking down the other parts.
+pub fn run_part(implementation: Part, input: &Input) -> Outcome {
+    match panic::catch_unwind(|| implementation(input)) {
+        Ok(Ok(answer)) => Outcome::Answer(answer),
+        Ok(Err(error)) => Outcome::Error(format!("{:#}", error)),
+        Err(panic) => Outcome::Error(format!("Panicked: {}", panic_message(&panic))),
+    }
+}
+
+/// Get the parts of a day that have been implemented, by part number
+pub fn implemented_parts(solution: &Solution) -> impl Iterator<Item = (u8, Part)> {
+    [(1, solution.part1), (2, solution.part2)]
+        .into_iter()
+        .filter_map(|(part, implementation)| Some((part, implementation?)))
+}
+
+/// Get the message a panic was raised with
+fn panic_message(panic: &Box<dyn Any + Send>) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+/// Run the implemented parts of the days on their inputs and report the answers.
+///
+/// Fails if any of the parts failed, after all results have been reported.
+pub fn run(days: Days, year: Option<u16>, format: Format, limits: &Limits) -> Result<()> {
+    let mut reporter = Reporter::new(format, std::io::stdout().lock());
+    for solution in days.solutions(year)? {
+        for result in run_solution(solution, limits) {
+            reporter.report(result)?;
+        }
+    }
+    let results = reporter.finish()?;
+    let failed = results
+        .iter()
+        .filter(|result| !result.outcome.is_success())
+        .count();
+    if failed > 0 {
+        bail!("{} of {} parts failed", failed, results.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{input_path, Days};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_parse_days() {
+        // expect "all" and day numbers to be accepted
+        assert_eq!(Days::All, Days::from_str("all").unwrap());
+        assert_eq!(Days::Day(7), Days::from_str("7").unwrap());
+        assert!(Days::from_str("seven").is_err());
+    }
+
+    #[test]
+    fn test_solutions() {
+        // expect a single day to only select that day
+        let solutions = Days::Day(7).solutions(Some(2022)).unwrap();
+        assert_eq!(1, solutions.len());
+        assert_eq!((2022, 7), (solutions[0].year, solutions[0].day));
+
+        // and a missing day to be an error
+        assert!(Days::Day(26).solutions(Some(2022)).is_err());
+        assert!(Days::Day(1).solutions(Some(2015)).is_err());
+
+        // and all days of a year to only select that year
+        assert!(Days::All
+            .solutions(Some(2022))
+            .unwrap()
+            .iter()
+            .all(|solution| solution.year == 2022));
+    }
+
+    #[test]
+    fn test_input_path() {
+        // expect the input to be looked up in the day's directory under the year
+        let solution = Days::Day(7).solutions(Some(2022)).unwrap()[0];
+        assert_eq!("2022/day07/input", input_path(solution));
+    }
+}
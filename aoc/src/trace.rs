@@ -0,0 +1,135 @@
+//! Tracing of what the solutions are doing, to stderr or to a file
+use anyhow::{anyhow, Result};
+use clap::Args;
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::{DefaultFields, FmtSpan, Format};
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::fmt::SubscriberBuilder;
+
+use util::trace::Level;
+
+/// The tracing options of the runner, kept to pass them on to child processes
+static TRACING: OnceLock<Tracing> = OnceLock::new();
+
+/// Options for tracing the solutions
+#[derive(Clone, Debug, Default, Args)]
+pub struct Tracing {
+    /// Trace what the solutions do at a level: error, warn, info, debug or trace
+    #[arg(long = "trace", global = true)]
+    pub level: Option<Level>,
+    /// Write the trace to a file instead of stderr
+    #[arg(long = "trace-file", global = true, requires = "level")]
+    pub file: Option<PathBuf>,
+}
+
+impl Tracing {
+    /// Start tracing if a level is given.
+    ///
+    /// Child processes append to the trace file, while the runner starts a new one.
+    pub fn init(self, child: bool) -> Result<()> {
+        let tracing = TRACING.get_or_init(|| self);
+        let level = match tracing.level {
+            Some(level) => level,
+            None => return Ok(()),
+        };
+        subscriber(level, writer(tracing.file.as_deref(), child)?)
+            .try_init()
+            .map_err(|error| anyhow!("Could not start tracing: {}", error))
+    }
+}
+
+/// Get the writer for the trace, a file shared by all threads or stderr
+fn writer(file: Option<&Path>, append: bool) -> Result<BoxMakeWriter> {
+    Ok(match file {
+        Some(path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(append)
+                .write(true)
+                .truncate(!append)
+                .open(path)?;
+            BoxMakeWriter::new(Mutex::new(file))
+        }
+        None => BoxMakeWriter::new(std::io::stderr),
+    })
+}
+
+/// Build the subscriber writing the events and closed spans up to a level
+fn subscriber(
+    level: Level,
+    writer: BoxMakeWriter,
+) -> SubscriberBuilder<DefaultFields, Format, LevelFilter, BoxMakeWriter> {
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(writer)
+}
+
+/// The arguments passing the tracing options on to a child process
+pub fn child_args() -> Vec<String> {
+    let tracing = TRACING.get().cloned().unwrap_or_default();
+    let level = tracing
+        .level
+        .map(|level| vec!["--trace".to_string(), level.to_string()]);
+    let file = tracing.file.map(|file| {
+        vec![
+            "--trace-file".to_string(),
+            file.to_string_lossy().into_owned(),
+        ]
+    });
+    level.into_iter().chain(file).flatten().collect()
+}
+
+/// Whether the trace is written to stderr, so the stderr of child processes has to be passed on
+pub fn to_stderr() -> bool {
+    TRACING
+        .get()
+        .is_some_and(|tracing| tracing.level.is_some() && tracing.file.is_none())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{subscriber, writer};
+    use std::fs;
+    use tracing_subscriber::util::SubscriberInitExt;
+    use util::trace::{self, Level};
+
+    #[test]
+    fn test_trace_file() {
+        // given a trace file that already has some content
+        let path = std::env::temp_dir().join(format!("aoc-trace-{}", std::process::id()));
+        fs::write(&path, "previous run\n").unwrap();
+
+        // when tracing a part with an event to it, and appending a child's event
+        for (append, message) in [(false, "first"), (true, "second")] {
+            let subscriber = subscriber(Level::DEBUG, writer(Some(&path), append).unwrap());
+            let _subscriber = subscriber.finish().set_default();
+            let _span = trace::part_span(2022, 1, 2).entered();
+            trace::debug!(count = 3, "Running the {} part", message);
+            trace::trace!("Too detailed");
+        }
+        let content = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // expect the file to be started over, with the events in their span and the closed spans
+        assert!(!content.contains("previous run"), "{}", content);
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(4, lines.len(), "{}", content);
+        for (line, message) in [(lines[0], "first"), (lines[2], "second")] {
+            assert!(line.contains("DEBUG"), "{}", line);
+            assert!(line.contains("part{year=2022 day=1 part=2}"), "{}", line);
+            assert!(
+                line.contains(&format!("Running the {} part count=3", message)),
+                "{}",
+                line
+            );
+        }
+        for line in [lines[1], lines[3]] {
+            assert!(line.contains("part{year=2022 day=1 part=2}: "), "{}", line);
+            assert!(line.contains("close"), "{}", line);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Watch mode, re-running a day whenever its sources, input or fixtures change
+use anyhow::{anyhow, Result};
+use inotify::{Inotify, WatchMask};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use util::registry::Solution;
+
+use crate::report::Outcome;
+
+/// How long to wait for more changes after the first one, editors tend to write files in steps
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// The answers of a run, by part
+type Answers = BTreeMap<u8, Outcome>;
+
+/// A line of JSON output from `aoc run`
+#[derive(Deserialize)]
+struct RunResult {
+    part: u8,
+    status: String,
+    answer: Option<String>,
+    error: Option<String>,
+}
+
+/// Get the directories to watch for a day: the day itself, including its input and fixtures,
+/// and `util`, along with all their subdirectories
+pub fn watched_directories(root: &Path, solution: &Solution) -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+    let mut pending = vec![
+        root.join(format!("{}/day{:02}", solution.year, solution.day)),
+        root.join("util"),
+    ];
+    while let Some(directory) = pending.pop() {
+        if let Ok(entries) = fs::read_dir(&directory) {
+            pending.extend(
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir()),
+            );
+            directories.push(directory);
+        }
+    }
+    directories.sort();
+    directories
+}
+
+/// Describe how the answers changed since the previous run, one line or block per part
+pub fn diff_answers(previous: Option<&Answers>, current: &Answers) -> String {
+    current
+        .iter()
+        .map(|(part, outcome)| {
+            let previous = previous.and_then(|previous| previous.get(part));
+            let change = match (previous, outcome) {
+                (None, _) => "",
+                (Some(previous), current) if previous == current => " (unchanged)",
+                (Some(_), _) => " (changed)",
+            };
+            match outcome {
+                Outcome::Answer(answer) if answer.contains('\n') => {
+                    format!("Part {}{}:\n{}", part, change, answer.trim_end())
+                }
+                Outcome::Answer(answer) => match previous {
+                    Some(Outcome::Answer(previous)) if previous != answer => {
+                        format!("Part {}: {} (was {})", part, answer, previous)
+                    }
+                    _ => format!("Part {}: {}{}", part, answer, change),
+                },
+                outcome => format!(
+                    "Part {}: error: {}{}",
+                    part,
+                    outcome.error().unwrap_or_default(),
+                    change
+                ),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Run cargo with some arguments, returning whether it succeeded
+fn cargo(args: &[&str]) -> Result<bool> {
+    Ok(Command::new("cargo").args(args).status()?.success())
+}
+
+/// Build the day, run its example tests and then run it on the real input
+fn run_once(solution: &Solution) -> Result<Option<Answers>> {
+    let package = format!("aoc{}-day{:02}", solution.year, solution.day);
+    if !cargo(&["build", "--quiet", "-p", &package])? {
+        println!("Build failed");
+        return Ok(None);
+    }
+    if !cargo(&["test", "--quiet", "-p", &package])? {
+        println!("Example tests failed");
+    }
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "-p", "aoc", "--", "run"])
+        .arg(solution.day.to_string())
+        .args(["--year", &solution.year.to_string(), "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()?;
+    let answers = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| {
+            let result: RunResult = serde_json::from_str(line)?;
+            let outcome = match (result.status.as_str(), result.answer, result.error) {
+                (_, Some(answer), _) => Outcome::Answer(answer),
+                ("timeout", None, _) => Outcome::Timeout,
+                ("out_of_memory", None, _) => Outcome::OutOfMemory,
+                (_, None, error) => Outcome::Error(error.unwrap_or_default()),
+            };
+            Ok((result.part, outcome))
+        })
+        .collect::<Result<Answers>>()?;
+    Ok(Some(answers))
+}
+
+/// Add watches for all directories of a day, picking up any new ones
+fn add_watches(inotify: &mut Inotify, solution: &Solution) -> Result<()> {
+    let mask = WatchMask::CLOSE_WRITE
+        | WatchMask::CREATE
+        | WatchMask::DELETE
+        | WatchMask::MOVED_TO
+        | WatchMask::MOVED_FROM;
+    for directory in watched_directories(Path::new("."), solution) {
+        inotify.watches().add(&directory, mask)?;
+    }
+    Ok(())
+}
+
+/// Block until something changes, then wait for the changes to settle
+fn wait_for_changes(inotify: &mut Inotify, buffer: &mut [u8]) -> Result<()> {
+    inotify.read_events_blocking(buffer)?;
+    loop {
+        thread::sleep(DEBOUNCE);
+        match inotify
+            .read_events(buffer)
+            .map(|mut events| events.next().is_some())
+        {
+            Ok(true) => continue,
+            Ok(false) => return Ok(()),
+            Err(error) if error.kind() == ErrorKind::WouldBlock => return Ok(()),
+            Err(error) => return Err(error.into()),
+        }
+    }
+}
+
+/// Re-run a day every time its sources, `util`, its input or its fixtures change
+pub fn watch(solution: &Solution) -> Result<()> {
+    if !Path::new("util").is_dir() {
+        return Err(anyhow!(
+            "Watch mode has to be started in the root of the workspace"
+        ));
+    }
+    let mut inotify = Inotify::init()?;
+    let mut buffer = [0; 4096];
+    let mut previous = None;
+    loop {
+        add_watches(&mut inotify, solution)?;
+        println!(
+            "Running {} day {}: {}",
+            solution.year, solution.day, solution.title
+        );
+        if let Some(answers) = run_once(solution)? {
+            println!("{}", diff_answers(previous.as_ref(), &answers));
+            previous = Some(answers);
+        }
+        println!("Waiting for changes...");
+        wait_for_changes(&mut inotify, &mut buffer)?;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{diff_answers, watched_directories, Answers};
+    use crate::report::Outcome;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use crate::run::Days;
+
+    fn answers(part1: Outcome, part2: Outcome) -> Answers {
+        [(1, part1), (2, part2)].into_iter().collect()
+    }
+
+    #[test]
+    fn test_diff_first_run() {
+        // given answers without a previous run
+        let current = answers(
+            Outcome::Answer("24000".to_string()),
+            Outcome::Error("No calories".to_string()),
+        );
+
+        // expect the answers to be shown as they are
+        assert_eq!(
+            "Part 1: 24000\nPart 2: error: No calories",
+            diff_answers(None, &current)
+        );
+    }
+
+    #[test]
+    fn test_diff_changes() {
+        // given a previous run and a run where one answer changed
+        let previous = answers(
+            Outcome::Answer("24000".to_string()),
+            Outcome::Answer("#.\n.#\n".to_string()),
+        );
+        let current = answers(
+            Outcome::Answer("25000".to_string()),
+            Outcome::Answer("#.\n.#\n".to_string()),
+        );
+
+        // expect the changed answer to show the previous answer
+        assert_eq!(
+            "Part 1: 25000 (was 24000)\nPart 2 (unchanged):\n#.\n.#",
+            diff_answers(Some(&previous), &current)
+        );
+    }
+
+    #[test]
+    fn test_watched_directories() {
+        // given the solution of a day, relative to the root of the workspace
+        let root = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+        let solution = Days::from_str("10").unwrap().solutions(Some(2022)).unwrap()[0];
+
+        // expect the day, its sources and util to be watched
+        let directories = watched_directories(root, solution);
+        for directory in ["2022/day10", "2022/day10/src", "util", "util/src"] {
+            assert!(directories.contains(&root.join(directory)));
+        }
+    }
+}
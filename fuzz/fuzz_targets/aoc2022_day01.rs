@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use util::Input;
+
+fuzz_target!(|data: &[u8]| {
+    let input = Input::from(String::from_utf8_lossy(data).into_owned());
+    let _ = aoc2022_day01::part1(&input);
+    let _ = aoc2022_day01::part2(&input);
+});
@@ -1,20 +1,39 @@
 #!/bin/bash
-if [[ "$#" != "1" ]];
+if [[ "$#" != "2" ]];
 then
-  echo "Usage: $0 DAY"
+  echo "Usage: $0 YEAR DAY"
   echo "Copies the template to a new day and downloads the puzzle input"
   exit 1
 fi
 source .env
-day=$1
+year=$1
+day=$2
 prefixed=$(printf '%02d' $day)
-target="day${prefixed}"
-cp -r template day${prefixed}
-sed -i "s/dayXX/day${prefixed}/" ${target}/Cargo.toml ${target}/src/main.rs
+target="${year}/day${prefixed}"
+title=$(curl -s "https://adventofcode.com/${year}/day/${day}" | sed -n "s|.*<h2>--- Day ${day}: \(.*\) ---</h2>.*|\1|p")
+mkdir -p ${year}
+cp -r template ${target}
+sed -i -e "s/YYYY/${year}/g" -e "s/dayXX/day${prefixed}/g" ${target}/Cargo.toml ${target}/src/main.rs ${target}/src/lib.rs
+# Escape the title for the Rust string literal first and then for the sed replacement
+escaped=$(printf '%s' "${title}" | sed -e 's/[\\"]/\\&/g' -e 's/[\\&|]/\\&/g')
+sed -i -e "s/DAY_NUMBER/${day}/" -e "s|DAY_TITLE|${escaped}|" ${target}/src/lib.rs
 
-head -n -1 Cargo.toml >Cargo.toml.new
-echo "  \"day${prefixed}\"," >>Cargo.toml.new
-echo "]" >>Cargo.toml.new
-mv Cargo.toml.new Cargo.toml
+# The workspace picks up every YEAR/dayNN directory and the runner every day it depends on
+echo "aoc${year}-day${prefixed} = { path = \"../${target}\" }" >>aoc/Cargo.toml
 
-exec curl "https://adventofcode.com/2022/day/${1}/input" -H "Cookie: session=${AOC_SESSION}" -o "day${prefixed}/input"
+# Every day gets a fuzz target feeding arbitrary input to both parts, its dependency goes after the other days
+package="aoc${year}-day${prefixed}"
+awk -v dependency="${package} = { path = \"../${target}\" }" \
+  '!/^aoc[0-9]+-day[0-9]+ = / && days && !added { print dependency; added = 1 }
+   { print } /^aoc[0-9]+-day[0-9]+ = / { days = 1 }' fuzz/Cargo.toml >fuzz/Cargo.toml.new
+mv fuzz/Cargo.toml.new fuzz/Cargo.toml
+printf '\n[[bin]]\nname = "%s"\npath = "fuzz_targets/%s.rs"\ntest = false\ndoc = false\n' ${package} ${package//-/_} >>fuzz/Cargo.toml
+sed -e "s/aoc2022_day01/${package//-/_}/g" fuzz/fuzz_targets/aoc2022_day01.rs >fuzz/fuzz_targets/${package//-/_}.rs
+
+curl "https://adventofcode.com/${year}/day/${day}/input" -H "Cookie: session=${AOC_SESSION}" -o "${target}/input" || exit 1
+
+# Inputs may not be published, keep an encrypted copy to commit if there is a key
+if [[ -n "${AOC_INPUT_KEY}" ]];
+then
+  cargo run -q -p aoc -- inputs encrypt ${day} --year ${year}
+fi
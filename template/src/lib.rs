@@ -0,0 +1,51 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::str::FromStr;
+
+use util::Input;
+
+// Uncomment the parts once they are implemented, until then they show up as missing in `aoc list`
+util::register! {
+    year: YYYY,
+    day: DAY_NUMBER,
+    title: "DAY_TITLE",
+    // part1: part1,
+    // part2: part2,
+}
+
+pub fn part1(input: &Input) -> Result<u32> {
+    Ok(0)
+}
+
+pub fn part2(input: &Input) -> Result<u32> {
+    Ok(0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{part1, part2};
+    use anyhow::Result;
+    use util::Input;
+
+    #[test]
+    pub fn test_part1() -> Result<()> {
+        let input = Input::from_lines([
+        ]);
+        assert_eq!(part1(&input).unwrap(), 0);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_part2() -> Result<()> {
+        let input = Input::from_lines([
+        ]);
+        assert_eq!(part2(&input).unwrap(), 0);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_fixtures() -> Result<()> {
+        // expect both parts to still give the answers saved with the examples from the scratch mode
+        util::check_fixtures!(part1, part2)
+    }
+}
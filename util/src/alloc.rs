@@ -0,0 +1,164 @@
+//! Counting of heap allocations, to see how much memory parsing and solving take.
+//!
+//! The counting allocator is only installed as the global allocator with the `count-allocations`
+//! feature, without it [`measure`] doesn't measure anything.
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{self, Display, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+/// The allocations made while running some code
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocations {
+    /// The number of allocations, including reallocations
+    pub count: u64,
+    /// The number of bytes allocated
+    pub bytes: u64,
+    /// The most bytes that were allocated at the same time, on top of what already was
+    pub peak: u64,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} bytes, peak {} bytes",
+            self.count, self.bytes, self.peak
+        )
+    }
+}
+
+/// An allocator counting the allocations made through the system allocator
+pub struct CountingAllocator {
+    count: AtomicU64,
+    bytes: AtomicU64,
+    live: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> CountingAllocator {
+        CountingAllocator {
+            count: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            live: AtomicU64::new(0),
+            peak: AtomicU64::new(0),
+        }
+    }
+
+    /// Measure the allocations made by a function
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Allocations) {
+        let count = self.count.load(Ordering::SeqCst);
+        let bytes = self.bytes.load(Ordering::SeqCst);
+        let live = self.live.load(Ordering::SeqCst);
+        self.peak.store(live, Ordering::SeqCst);
+        let result = f();
+        let allocations = Allocations {
+            count: self.count.load(Ordering::SeqCst) - count,
+            bytes: self.bytes.load(Ordering::SeqCst) - bytes,
+            peak: self.peak.load(Ordering::SeqCst).saturating_sub(live),
+        };
+        (result, allocations)
+    }
+
+    fn allocated(&self, size: usize) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        self.bytes.fetch_add(size as u64, Ordering::SeqCst);
+        let live = self.live.fetch_add(size as u64, Ordering::SeqCst) + size as u64;
+        self.peak.fetch_max(live, Ordering::SeqCst);
+    }
+
+    fn deallocated(&self, size: usize) {
+        self.live.fetch_sub(size as u64, Ordering::SeqCst);
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> CountingAllocator {
+        CountingAllocator::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            self.allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            self.allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        self.deallocated(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            self.deallocated(layout.size());
+            self.allocated(new_size);
+        }
+        new_pointer
+    }
+}
+
+/// Measure the allocations made by a function, if allocations are counted
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let (result, allocations) = ALLOCATOR.measure(f);
+        (result, Some(allocations))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Allocations, CountingAllocator};
+    use std::alloc::{GlobalAlloc, Layout};
+
+    #[test]
+    fn test_measure() {
+        // given an allocator with some memory allocated up front
+        let allocator = CountingAllocator::new();
+        let small = Layout::from_size_align(16, 8).unwrap();
+        let large = Layout::from_size_align(1024, 8).unwrap();
+        let before = unsafe { allocator.alloc(small) };
+
+        // when measuring code that allocates, grows and frees memory
+        let ((), allocations) = allocator.measure(|| unsafe {
+            let first = allocator.alloc(large);
+            let second = allocator.alloc(small);
+            let second = allocator.realloc(second, small, 64);
+            allocator.dealloc(first, large);
+            allocator.dealloc(second, Layout::from_size_align(64, 8).unwrap());
+        });
+        unsafe { allocator.dealloc(before, small) };
+
+        // expect every allocation to count, and the peak to not include what came before
+        assert_eq!(
+            Allocations {
+                count: 3,
+                bytes: 1024 + 16 + 64,
+                peak: 1024 + 64,
+            },
+            allocations
+        );
+    }
+}
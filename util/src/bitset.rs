@@ -0,0 +1,363 @@
+//! Compact sets of small integers, like the letters of an alphabet, stored as the bits of a word.
+//!
+//! Sets of letters are built from a `&str` with an [`Alphabet`], which numbers its characters:
+//!
+//! ```
+//! use util::bitset::{Alphabet, BitSet};
+//!
+//! let first = BitSet::<u64>::from_chars("vJrwpWtwJgWr", &Alphabet::LETTERS).unwrap();
+//! let second = BitSet::from_chars("hcsFMMfFFhFp", &Alphabet::LETTERS).unwrap();
+//! let shared = first & second;
+//! let shared = shared.iter().filter_map(|index| Alphabet::LETTERS.char(index));
+//! assert_eq!("p", shared.collect::<String>());
+//! ```
+use anyhow::{anyhow, Result};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+use std::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+
+/// A word to store the bits of a set in
+pub trait Word:
+    Copy
+    + Eq
+    + Hash
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+{
+    /// The number of bits, and so the number of values a set can hold
+    const BITS: u32;
+    const ZERO: Self;
+
+    /// Get a word with only the bit at an index set
+    fn bit(index: usize) -> Self;
+    fn count_ones(self) -> u32;
+    fn trailing_zeros(self) -> u32;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {
+        $(impl Word for $t {
+            const BITS: u32 = <$t>::BITS;
+            const ZERO: Self = 0;
+
+            fn bit(index: usize) -> Self {
+                1 << index
+            }
+
+            fn count_ones(self) -> u32 {
+                <$t>::count_ones(self)
+            }
+
+            fn trailing_zeros(self) -> u32 {
+                <$t>::trailing_zeros(self)
+            }
+        })*
+    };
+}
+
+impl_word!(u64, u128);
+
+/// A set of integers below the number of bits of the word it is stored in
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitSet<W = u64>(W);
+
+impl<W: Word> BitSet<W> {
+    /// The largest number of values a set can hold
+    pub const CAPACITY: usize = W::BITS as usize;
+
+    pub fn new() -> BitSet<W> {
+        BitSet(W::ZERO)
+    }
+
+    /// Create a set of the characters of a string, numbered by an alphabet
+    pub fn from_chars(chars: &str, alphabet: &Alphabet) -> Result<BitSet<W>> {
+        chars.chars().try_fold(BitSet::new(), |mut set, c| {
+            let index = alphabet
+                .index(c)
+                .filter(|&index| index < Self::CAPACITY)
+                .ok_or_else(|| anyhow!("Character {:?} does not fit in the set", c))?;
+            set.insert(index);
+            Ok(set)
+        })
+    }
+
+    /// Get the word the set is stored in
+    pub fn bits(&self) -> W {
+        self.0
+    }
+
+    /// Add a value to the set, which must be below [`Self::CAPACITY`]
+    pub fn insert(&mut self, value: usize) {
+        self.0 = self.0 | Self::bit(value);
+    }
+
+    pub fn remove(&mut self, value: usize) {
+        self.0 = self.0 & !Self::bit(value);
+    }
+
+    /// Add a value if it isn't in the set, remove it if it is
+    pub fn toggle(&mut self, value: usize) {
+        self.0 = self.0 ^ Self::bit(value);
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        value < Self::CAPACITY && self.0 & W::bit(value) != W::ZERO
+    }
+
+    /// Get the number of values in the set
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == W::ZERO
+    }
+
+    pub fn union(&self, other: &BitSet<W>) -> BitSet<W> {
+        BitSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &BitSet<W>) -> BitSet<W> {
+        BitSet(self.0 & other.0)
+    }
+
+    /// Get the values in this set that aren't in the other
+    pub fn difference(&self, other: &BitSet<W>) -> BitSet<W> {
+        BitSet(self.0 & !other.0)
+    }
+
+    /// Get the values that are in only one of the sets
+    pub fn symmetric_difference(&self, other: &BitSet<W>) -> BitSet<W> {
+        BitSet(self.0 ^ other.0)
+    }
+
+    /// Iterate over the values in the set, from low to high
+    pub fn iter(&self) -> Iter<W> {
+        Iter(self.0)
+    }
+
+    fn bit(value: usize) -> W {
+        assert!(
+            value < Self::CAPACITY,
+            "{} does not fit in a set of {} values",
+            value,
+            Self::CAPACITY
+        );
+        W::bit(value)
+    }
+}
+
+impl<W: Word> Default for BitSet<W> {
+    fn default() -> BitSet<W> {
+        BitSet::new()
+    }
+}
+
+impl<W: Word> Debug for BitSet<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<W: Word> BitAnd for BitSet<W> {
+    type Output = BitSet<W>;
+
+    fn bitand(self, other: BitSet<W>) -> BitSet<W> {
+        self.intersection(&other)
+    }
+}
+
+impl<W: Word> BitOr for BitSet<W> {
+    type Output = BitSet<W>;
+
+    fn bitor(self, other: BitSet<W>) -> BitSet<W> {
+        self.union(&other)
+    }
+}
+
+impl<W: Word> BitXor for BitSet<W> {
+    type Output = BitSet<W>;
+
+    fn bitxor(self, other: BitSet<W>) -> BitSet<W> {
+        self.symmetric_difference(&other)
+    }
+}
+
+impl<W: Word> Sub for BitSet<W> {
+    type Output = BitSet<W>;
+
+    fn sub(self, other: BitSet<W>) -> BitSet<W> {
+        self.difference(&other)
+    }
+}
+
+impl<W: Word> FromIterator<usize> for BitSet<W> {
+    fn from_iter<I: IntoIterator<Item = usize>>(values: I) -> BitSet<W> {
+        let mut set = BitSet::new();
+        for value in values {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+impl<W: Word> IntoIterator for BitSet<W> {
+    type Item = usize;
+    type IntoIter = Iter<W>;
+
+    fn into_iter(self) -> Iter<W> {
+        self.iter()
+    }
+}
+
+/// An iterator over the values in a [`BitSet`]
+pub struct Iter<W>(W);
+
+impl<W: Word> Iterator for Iter<W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == W::ZERO {
+            return None;
+        }
+        let value = self.0.trailing_zeros() as usize;
+        self.0 = self.0 ^ W::bit(value);
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+/// The ASCII characters that can be put in a set, numbered in order
+pub struct Alphabet {
+    chars: &'static str,
+    /// The number of every ASCII character, or `u8::MAX` if it isn't in the alphabet
+    indices: [u8; 128],
+}
+
+impl Alphabet {
+    /// The lowercase letters, numbered from 0
+    pub const LOWERCASE: Alphabet = Alphabet::new("abcdefghijklmnopqrstuvwxyz");
+    /// The uppercase letters, numbered from 0
+    pub const UPPERCASE: Alphabet = Alphabet::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    /// The lowercase letters numbered from 0, followed by the uppercase letters numbered from 26
+    pub const LETTERS: Alphabet =
+        Alphabet::new("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    /// The digits, numbered by their value
+    pub const DIGITS: Alphabet = Alphabet::new("0123456789");
+
+    /// Create an alphabet of distinct ASCII characters
+    pub const fn new(chars: &'static str) -> Alphabet {
+        let bytes = chars.as_bytes();
+        let mut indices = [u8::MAX; 128];
+        let mut index = 0;
+        while index < bytes.len() {
+            let byte = bytes[index] as usize;
+            assert!(byte < 128, "An alphabet can only hold ASCII characters");
+            assert!(
+                indices[byte] == u8::MAX,
+                "An alphabet can't repeat characters"
+            );
+            indices[byte] = index as u8;
+            index += 1;
+        }
+        Alphabet { chars, indices }
+    }
+
+    /// Get the number of a character, if it is in the alphabet
+    pub fn index(&self, c: char) -> Option<usize> {
+        match self.indices.get(c as usize) {
+            Some(&index) if index != u8::MAX => Some(index as usize),
+            _ => None,
+        }
+    }
+
+    /// Get the character with a number
+    pub fn char(&self, index: usize) -> Option<char> {
+        self.chars.as_bytes().get(index).map(|&byte| byte as char)
+    }
+
+    /// Get the number of characters in the alphabet
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Alphabet, BitSet};
+
+    #[test]
+    fn test_set_operations() {
+        // given two sets
+        let left = BitSet::<u64>::from_iter([1, 3, 5, 63]);
+        let right = BitSet::from_iter([3, 4, 5]);
+
+        // expect them to be combined
+        assert_eq!(
+            vec![1, 3, 4, 5, 63],
+            (left | right).iter().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![3, 5], (left & right).iter().collect::<Vec<_>>());
+        assert_eq!(vec![1, 63], (left - right).iter().collect::<Vec<_>>());
+        assert_eq!(vec![1, 4, 63], (left ^ right).iter().collect::<Vec<_>>());
+        assert_eq!(4, left.len());
+        assert!(left.contains(63) && !left.contains(64) && !left.contains(2));
+        assert!((left & BitSet::from_iter([0])).is_empty());
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        // given a set of 128 bits
+        let mut set = BitSet::<u128>::new();
+
+        // when values are added and removed
+        set.insert(127);
+        set.insert(0);
+        set.toggle(64);
+        set.remove(0);
+        set.toggle(127);
+
+        // expect only the toggled on value to be left
+        assert_eq!(vec![64], set.iter().collect::<Vec<_>>());
+        assert_eq!("{64}", format!("{:?}", set));
+    }
+
+    #[test]
+    fn test_from_chars() {
+        // given some letters
+        let set = BitSet::<u64>::from_chars("aAzZa", &Alphabet::LETTERS).unwrap();
+
+        // expect them to be numbered by the alphabet
+        assert_eq!(vec![0, 25, 26, 51], set.iter().collect::<Vec<_>>());
+        assert_eq!(
+            "azAZ",
+            set.iter()
+                .filter_map(|index| Alphabet::LETTERS.char(index))
+                .collect::<String>()
+        );
+
+        // and characters outside of the alphabet to be refused
+        assert!(BitSet::<u64>::from_chars("ab1", &Alphabet::LOWERCASE).is_err());
+        assert!(BitSet::<u64>::from_chars("é", &Alphabet::LOWERCASE).is_err());
+    }
+
+    #[test]
+    fn test_alphabet() {
+        let alphabet = Alphabet::new("#.");
+        assert_eq!(2, alphabet.len());
+        assert_eq!(Some(1), alphabet.index('.'));
+        assert_eq!(None, alphabet.index('a'));
+        assert_eq!(Some('#'), alphabet.char(0));
+        assert_eq!(None, alphabet.char(2));
+    }
+}
@@ -0,0 +1,153 @@
+//! Parsing of drawings that are read in columns, like the stacks of crates of 2022 day 5:
+//!
+//! ```text
+//!     [D]
+//! [N] [C]
+//! [Z] [M] [P]
+//!  1   2   3
+//! ```
+//!
+//! The last line of such a drawing labels the columns, and the characters above a label make up
+//! its column.
+use anyhow::{anyhow, Result};
+
+/// Turn rows into columns, where rows may have different lengths. Every column only holds the
+/// elements of the rows that are long enough to reach it.
+pub fn transpose<T, R>(rows: impl IntoIterator<Item = R>) -> Vec<Vec<T>>
+where
+    R: IntoIterator<Item = T>,
+{
+    let mut columns: Vec<Vec<T>> = Vec::new();
+    for row in rows {
+        for (index, element) in row.into_iter().enumerate() {
+            match columns.get_mut(index) {
+                Some(column) => column.push(element),
+                None => columns.push(vec![element]),
+            }
+        }
+    }
+    columns
+}
+
+/// Get the position, in characters, and the text of every label in a row of labels
+pub fn label_positions(labels: &str) -> Vec<(usize, &str)> {
+    let mut positions = Vec::new();
+    let mut start = None;
+    for (position, (index, c)) in labels
+        .char_indices()
+        .chain([(labels.len(), ' ')])
+        .enumerate()
+    {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some((position, index)),
+            (Some((label_position, label_index)), true) => {
+                positions.push((label_position, &labels[label_index..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    positions
+}
+
+/// A drawing of columns with a row of labels at the bottom
+#[derive(Debug, PartialEq, Eq)]
+pub struct Drawing {
+    pub labels: Vec<String>,
+    /// The characters above the labels in every row, from the top, or `None` where there is
+    /// only whitespace
+    pub rows: Vec<Vec<Option<char>>>,
+}
+
+impl Drawing {
+    /// Parse a drawing, where the first character of a label is below its column
+    pub fn parse(drawing: &str) -> Result<Drawing> {
+        let mut lines = drawing.trim_end().lines().collect::<Vec<_>>();
+        let labels = lines
+            .pop()
+            .map(label_positions)
+            .filter(|labels| !labels.is_empty())
+            .ok_or_else(|| anyhow!("No labels below the drawing"))?;
+        let rows = lines
+            .into_iter()
+            .map(|line| {
+                let chars = line.chars().collect::<Vec<_>>();
+                labels
+                    .iter()
+                    .map(|&(position, _)| {
+                        chars.get(position).copied().filter(|c| !c.is_whitespace())
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Drawing {
+            labels: labels
+                .into_iter()
+                .map(|(_, label)| label.to_string())
+                .collect(),
+            rows,
+        })
+    }
+
+    /// Get the characters of every column from the top, leaving out whitespace
+    pub fn columns(&self) -> Vec<Vec<char>> {
+        let mut columns = transpose(self.rows.iter().map(|row| row.iter().copied()))
+            .into_iter()
+            .map(|column| column.into_iter().flatten().collect())
+            .collect::<Vec<_>>();
+        // Without any rows there is nothing to transpose, but there still are columns
+        columns.resize(self.labels.len(), Vec::new());
+        columns
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{label_positions, transpose, Drawing};
+
+    #[test]
+    fn test_transpose() {
+        // given ragged rows
+        let rows = vec![vec![1, 2, 3], vec![4], vec![5, 6]];
+
+        // expect every column to hold the elements that reach it
+        assert_eq!(vec![vec![1, 4, 5], vec![2, 6], vec![3]], transpose(rows));
+        assert!(transpose(Vec::<Vec<u8>>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_label_positions() {
+        assert_eq!(
+            vec![(1, "1"), (5, "2"), (9, "3")],
+            label_positions(" 1   2   3 ")
+        );
+        assert_eq!(vec![(0, "a"), (2, "10")], label_positions("a 10"));
+        assert!(label_positions("   ").is_empty());
+    }
+
+    #[test]
+    fn test_drawing() {
+        // given the drawing of 2022 day 5, with trailing whitespace stripped from some lines
+        let drawing = "    [D]\n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n";
+
+        // when parsing it
+        let drawing = Drawing::parse(drawing).unwrap();
+
+        // expect the crates above every label
+        assert_eq!(vec!["1", "2", "3"], drawing.labels);
+        assert_eq!(vec![None, Some('D'), None], drawing.rows[0]);
+        assert_eq!(
+            vec![vec!['N', 'Z'], vec!['D', 'C', 'M'], vec!['P']],
+            drawing.columns()
+        );
+
+        // and a drawing without labels to be an error
+        assert!(Drawing::parse("  \n\n").is_err());
+
+        // and labels without a drawing to be empty columns
+        assert_eq!(
+            vec![Vec::<char>::new(); 2],
+            Drawing::parse("1 2").unwrap().columns()
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Finding where a simulation starts repeating itself, to skip ahead to far away steps.
+//!
+//! [`brent`] and [`floyd`] find the cycle of states that are repeated exactly, while only keeping
+//! a couple of states around. A [`History`] keeps every state, and finds the cycle of a projection
+//! of the state, like the shape of the top of a tower, while other parts, like its height, keep
+//! growing every cycle.
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating, and how often
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step that is repeated
+    pub start: usize,
+    /// The number of steps until a state is repeated
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Get the first step with the same state as a step
+    pub fn equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// Get the number of complete cycles before a step
+    pub fn cycles(&self, step: usize) -> usize {
+        step.saturating_sub(self.start) / self.length
+    }
+}
+
+/// Find the cycle of a sequence of states using Brent's algorithm, which steps less often than
+/// Floyd's. Never returns if no state is repeated.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length by moving the tortoise to the hare at every power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Find the start with the hare a cycle ahead of the tortoise
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Find the cycle of a sequence of states using Floyd's algorithm, with a hare going twice as fast
+/// as a tortoise. Never returns if no state is repeated.
+pub fn floyd<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // The tortoise is now a multiple of the length from the start, as far as the initial state is
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Every state of a simulation up to and including the first repeated one
+pub struct History<S> {
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S> History<S> {
+    /// Step through states until the projection of a state, hashed to remember it, is repeated.
+    /// Never returns if no projection is repeated.
+    pub fn detect<K: Hash + Eq>(
+        initial: S,
+        mut step: impl FnMut(&S) -> S,
+        mut projection: impl FnMut(&S) -> K,
+    ) -> History<S> {
+        let mut seen = HashMap::new();
+        let mut states = vec![initial];
+        loop {
+            let current = states.len() - 1;
+            match seen.entry(projection(&states[current])) {
+                Entry::Occupied(entry) => {
+                    let start = *entry.get();
+                    let cycle = Cycle {
+                        start,
+                        length: current - start,
+                    };
+                    return History { states, cycle };
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(current);
+                }
+            }
+            let next = step(&states[current]);
+            states.push(next);
+        }
+    }
+
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// Get the states up to and including the first repeated one
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// Get the state at a step, or the state of its first equivalent step if it is further than
+    /// the history goes
+    pub fn state_at(&self, step: usize) -> &S {
+        self.states
+            .get(step)
+            .unwrap_or_else(|| &self.states[self.cycle.equivalent(step)])
+    }
+
+    /// Get a value of the state at any step, for a value that changes by the same amount every
+    /// cycle, like the height of a tower
+    pub fn extrapolate(&self, step: usize, mut value: impl FnMut(&S) -> i64) -> i64 {
+        if let Some(state) = self.states.get(step) {
+            return value(state);
+        }
+        let Cycle { start, length } = self.cycle;
+        let growth = value(&self.states[start + length]) - value(&self.states[start]);
+        value(self.state_at(step)) + self.cycle.cycles(step) as i64 * growth
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{brent, floyd, Cycle, History};
+
+    /// Squaring modulo a number goes through some states before it starts repeating
+    fn square(x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    #[test]
+    fn test_brent_floyd() {
+        // given the states 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        let expected = Cycle {
+            start: 2,
+            length: 6,
+        };
+
+        // expect both algorithms to find the cycle
+        assert_eq!(expected, brent(3, square));
+        assert_eq!(expected, floyd(3, square));
+
+        // and a cycle from the start to be found too
+        let counter = Cycle {
+            start: 0,
+            length: 7,
+        };
+        assert_eq!(counter, brent(0, |x| (x + 1) % 7));
+        assert_eq!(counter, floyd(0, |x| (x + 1) % 7));
+    }
+
+    #[test]
+    fn test_history() {
+        // given the same sequence as a history
+        let mut projections = 0;
+        let history = History::detect(3, square, |&x| {
+            projections += 1;
+            x
+        });
+
+        // expect the same cycle, projecting every state once, and states further on to be found
+        // in it
+        assert_eq!(brent(3, square), history.cycle());
+        assert_eq!(9, history.states().len());
+        assert_eq!(9, projections);
+        assert_eq!(&5, history.state_at(4));
+        assert_eq!(&5, history.state_at(4 + 6 * 1000));
+        assert_eq!(5, history.cycle().equivalent(2 + 6 * 1_000_000 + 3));
+        assert_eq!(0, history.cycle().cycles(1));
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // given a wheel with 4 spokes which turns 1 spoke per step, and counts its turns
+        let history = History::detect(
+            (1, 0),
+            |&(spoke, turns)| ((spoke + 1) % 4, turns + (spoke == 3) as i64),
+            |&(spoke, _)| spoke,
+        );
+
+        // expect the turns to be counted even far ahead
+        assert_eq!(
+            Cycle {
+                start: 0,
+                length: 4
+            },
+            history.cycle()
+        );
+        assert_eq!(0, history.extrapolate(2, |&(_, turns)| turns));
+        assert_eq!(1, history.extrapolate(3, |&(_, turns)| turns));
+        assert_eq!(25, history.extrapolate(102, |&(_, turns)| turns));
+        assert_eq!(26, history.extrapolate(103, |&(_, turns)| turns));
+    }
+}
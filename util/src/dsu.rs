@@ -0,0 +1,285 @@
+//! Disjoint sets of elements, to find which parts of a grid or graph are connected.
+//!
+//! [`grid_components`] labels the regions of a grid of cells stored row by row, joining
+//! neighbouring cells that belong together, like the blocks of trees of the same height in day 8.
+//! [`graph_components`] does the same for graphs given by the neighbours of their nodes.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A disjoint-set union of the elements `0..len`, with path compression and union by rank
+#[derive(Clone, Debug)]
+pub struct Dsu {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    sets: usize,
+}
+
+impl Dsu {
+    /// Create a set for every element
+    pub fn new(len: usize) -> Dsu {
+        Dsu {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            sets: len,
+        }
+    }
+
+    /// Get the element representing the set of an element
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point everything on the way straight at the root, so it is found faster next time
+        let mut current = element;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Join the sets of two elements, returning false if they already were in the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // Hang the shallower tree under the deeper one, to keep the trees shallow
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    /// Check if two elements are in the same set
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Get the number of elements in the set of an element
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Get the number of disjoint sets
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number the sets in the order of their first element
+    pub fn components(&mut self) -> Components {
+        let mut numbers = HashMap::new();
+        let mut sizes = Vec::new();
+        let labels = (0..self.len())
+            .map(|element| {
+                let root = self.find(element);
+                *numbers.entry(root).or_insert_with(|| {
+                    sizes.push(self.sizes[root]);
+                    sizes.len() - 1
+                })
+            })
+            .collect();
+        Components { labels, sizes }
+    }
+}
+
+/// The connected components of a grid or graph, labelled from 0
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Components {
+    labels: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl Components {
+    /// Get the label of the component of an element
+    pub fn label(&self, element: usize) -> usize {
+        self.labels[element]
+    }
+
+    /// Get the labels of all elements, in order
+    pub fn labels(&self) -> &[usize] {
+        &self.labels
+    }
+
+    /// Get the number of elements in the component with a label
+    pub fn size(&self, label: usize) -> usize {
+        self.sizes[label]
+    }
+
+    /// Get the number of components
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Get the label and size of the biggest component, the first one if there are several
+    pub fn largest(&self) -> Option<(usize, usize)> {
+        self.sizes
+            .iter()
+            .copied()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, size)| size)
+    }
+
+    /// Get the elements of the component with a label
+    pub fn elements(&self, label: usize) -> impl Iterator<Item = usize> + '_ {
+        self.labels
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &other)| other == label)
+            .map(|(element, _)| element)
+    }
+}
+
+/// Find the regions of a grid of cells stored row by row, where `connected(a, b)` decides if two
+/// cells next to each other, horizontally or vertically, belong to the same region. A grid without
+/// width has no cells, so it has no regions.
+pub fn grid_components<T>(
+    cells: &[T],
+    width: usize,
+    connected: impl Fn(&T, &T) -> bool,
+) -> Components {
+    if width == 0 {
+        return Dsu::new(0).components();
+    }
+    let mut dsu = Dsu::new(cells.len());
+    for index in 0..cells.len() {
+        let right = index + 1;
+        if right % width != 0 && right < cells.len() && connected(&cells[index], &cells[right]) {
+            dsu.union(index, right);
+        }
+        let down = index + width;
+        if down < cells.len() && connected(&cells[index], &cells[down]) {
+            dsu.union(index, down);
+        }
+    }
+    dsu.components()
+}
+
+/// Find the connected components of a graph, where the neighbours of a node are connected to it.
+/// Elements of the components are the indexes of the nodes, and neighbours that aren't nodes are
+/// ignored.
+pub fn graph_components<N, I>(nodes: &[N], mut neighbours: impl FnMut(&N) -> I) -> Components
+where
+    N: Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let indexes: HashMap<&N, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node, index))
+        .collect();
+    let mut dsu = Dsu::new(nodes.len());
+    for (index, node) in nodes.iter().enumerate() {
+        for neighbour in neighbours(node) {
+            if let Some(&other) = indexes.get(&neighbour) {
+                dsu.union(index, other);
+            }
+        }
+    }
+    dsu.components()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{graph_components, grid_components, Dsu};
+
+    #[test]
+    fn test_dsu() {
+        // given 6 elements
+        let mut dsu = Dsu::new(6);
+
+        // when joining some of them
+        assert!(dsu.union(0, 1));
+        assert!(dsu.union(2, 3));
+        assert!(dsu.union(1, 3));
+        assert!(!dsu.union(0, 2));
+
+        // expect 3 sets to be left
+        assert_eq!(3, dsu.sets());
+        assert!(dsu.connected(0, 3));
+        assert!(!dsu.connected(0, 4));
+        assert_eq!(4, dsu.size(2));
+        assert_eq!(vec![0, 0, 0, 0, 1, 2], dsu.components().labels());
+    }
+
+    #[test]
+    fn test_grid_components() {
+        // given the forest of 2022 day 8
+        let forest = "3037325512653323354935390"
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .collect::<Vec<_>>();
+
+        // when finding the blocks of trees of the same height
+        let components = grid_components(&forest, 5, |a, b| a == b);
+
+        // expect the first of the largest blocks to be the 5's near the top
+        let (label, size) = components.largest().unwrap();
+        assert_eq!(3, size);
+        assert_eq!(
+            vec![6, 7, 11],
+            components.elements(label).collect::<Vec<_>>()
+        );
+
+        // and the 3's at the bottom left to be a block too
+        assert_eq!(components.label(15), components.label(20));
+        assert_eq!(3, components.size(components.label(15)));
+        assert_ne!(components.label(0), components.label(1));
+    }
+
+    #[test]
+    fn test_grid_components_odd_shapes() {
+        // given a grid without width and one with a partial last row
+        let cells = [1, 1, 1, 2, 2, 1, 2];
+
+        // expect no regions without width, and the partial row to be connected to the row above
+        assert_eq!(0, grid_components(&cells, 0, |a, b| a == b).count());
+        let components = grid_components(&cells, 5, |a, b| a == b);
+        assert_eq!(3, components.count());
+        assert_eq!(components.label(0), components.label(5));
+        assert_ne!(components.label(3), components.label(6));
+    }
+
+    #[test]
+    fn test_graph_components() {
+        // given two droplets of cubes, one of 2 cubes and one of 3
+        let cubes = [(0, 0, 0), (5, 5, 5), (0, 0, 1), (5, 6, 5), (5, 6, 6)];
+        let neighbours = |&(x, y, z): &(i32, i32, i32)| {
+            [
+                (x - 1, y, z),
+                (x + 1, y, z),
+                (x, y - 1, z),
+                (x, y + 1, z),
+                (x, y, z - 1),
+                (x, y, z + 1),
+            ]
+        };
+
+        // expect them to be found
+        let components = graph_components(&cubes, neighbours);
+        assert_eq!(2, components.count());
+        assert_eq!(vec![0, 1, 0, 1, 1], components.labels());
+        assert_eq!(Some((1, 3)), components.largest());
+    }
+}
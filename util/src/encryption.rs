@@ -0,0 +1,151 @@
+//! Encryption of puzzle inputs, so they can be committed without publishing them.
+//!
+//! Inputs are encrypted with [age](https://age-encryption.org) to `input.age` next to where the
+//! plain input would be, using an X25519 key from the `AOC_INPUT_KEY` environment variable or
+//! the `.env` file. Encrypted inputs can also be decrypted with the `age` command line tool.
+use age::secrecy::ExposeSecret;
+use age::x25519::Identity;
+use std::env;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The variable holding the key, in the environment or in `.env`
+pub const KEY_VARIABLE: &str = "AOC_INPUT_KEY";
+
+/// The extension of encrypted files
+pub const EXTENSION: &str = "age";
+
+/// Get the path of the encrypted version of a file
+pub fn encrypted_path(path: impl AsRef<Path>) -> PathBuf {
+    let mut path = path.as_ref().as_os_str().to_owned();
+    path.push(".");
+    path.push(EXTENSION);
+    PathBuf::from(path)
+}
+
+/// A key to encrypt and decrypt inputs with
+pub struct Key(Identity);
+
+impl Key {
+    /// Generate a new key
+    pub fn generate() -> Key {
+        Key(Identity::generate())
+    }
+
+    /// Load the key from the environment, or from `.env` in the current directory or any of its
+    /// parents
+    pub fn load() -> Result<Key> {
+        let key = match env::var(KEY_VARIABLE) {
+            Ok(key) => key,
+            Err(_) => find_in_dotenv()?,
+        };
+        Key::from_str(&key)
+    }
+
+    /// Encrypt some data so it can only be decrypted with this key
+    pub fn encrypt(&self, plain: &[u8]) -> Result<Vec<u8>> {
+        age::encrypt(&self.0.to_public(), plain).map_err(|error| Error::other(error.to_string()))
+    }
+
+    /// Decrypt data that was encrypted with this key
+    pub fn decrypt(&self, encrypted: &[u8]) -> Result<Vec<u8>> {
+        age::decrypt(&self.0, encrypted)
+            .map_err(|error| Error::new(ErrorKind::InvalidData, error.to_string()))
+    }
+}
+
+impl FromStr for Key {
+    type Err = Error;
+
+    fn from_str(key: &str) -> Result<Key> {
+        Identity::from_str(key.trim())
+            .map(Key)
+            .map_err(|error| Error::new(ErrorKind::InvalidInput, format!("Invalid key: {}", error)))
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.to_string().expose_secret())
+    }
+}
+
+/// Find the key in the closest `.env`
+fn find_in_dotenv() -> Result<String> {
+    let directory = env::current_dir()?;
+    directory
+        .ancestors()
+        .map(|directory| directory.join(".env"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .find_map(|dotenv| parse_dotenv(&dotenv, KEY_VARIABLE))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("No {} in the environment or .env", KEY_VARIABLE),
+            )
+        })
+}
+
+/// Get a variable from the contents of a `.env` file, which holds `NAME="value"` lines
+fn parse_dotenv(dotenv: &str, name: &str) -> Option<String> {
+    dotenv.lines().find_map(|line| {
+        let (variable, value) = line.trim().split_once('=')?;
+        (variable.trim_start_matches("export ").trim() == name).then(|| {
+            value
+                .trim()
+                .trim_matches('"')
+                .trim_matches('\'')
+                .to_string()
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{encrypted_path, parse_dotenv, Key};
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_round_trip() {
+        // given a key, and that key written out and read back
+        let key = Key::generate();
+        let same_key = Key::from_str(&key.to_string()).unwrap();
+
+        // expect data encrypted with one to be decrypted with the other
+        let encrypted = key.encrypt(b"1000\n2000\n").unwrap();
+        assert_ne!(b"1000\n2000\n".to_vec(), encrypted);
+        assert_eq!(
+            b"1000\n2000\n".to_vec(),
+            same_key.decrypt(&encrypted).unwrap()
+        );
+
+        // and not with another key
+        assert!(Key::generate().decrypt(&encrypted).is_err());
+    }
+
+    #[test]
+    fn test_parse_dotenv() {
+        // given a .env with a session and a key
+        let dotenv = "AOC_SESSION=\"abc\"\nexport AOC_INPUT_KEY='AGE-SECRET-KEY-1'\n";
+
+        // expect variables to be found without quotes
+        assert_eq!(Some("abc".to_string()), parse_dotenv(dotenv, "AOC_SESSION"));
+        assert_eq!(
+            Some("AGE-SECRET-KEY-1".to_string()),
+            parse_dotenv(dotenv, "AOC_INPUT_KEY")
+        );
+        assert_eq!(None, parse_dotenv(dotenv, "AOC_OTHER"));
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(
+            PathBuf::from("2022/day01/input.age"),
+            encrypted_path("2022/day01/input")
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Example fixtures, inputs saved in the `fixtures` directory of a day by `aoc repl` along with
+//! the answers the parts gave for them, so the tests of the day notice when an answer changes
+use anyhow::{bail, Context};
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::Input;
+
+/// The directory of a day the fixtures are kept in
+pub const DIRECTORY: &str = "fixtures";
+
+/// A saved example input
+pub struct Fixture {
+    pub name: String,
+    pub input: Input,
+    /// The expected answers to part 1 and 2, none for the parts that failed when it was saved
+    pub answers: [Option<String>; 2],
+}
+
+/// Check that both parts still give the saved answers for every fixture in the `fixtures`
+/// directory of the crate.
+///
+/// ```no_run
+/// # fn part1(_: &util::Input) -> anyhow::Result<u32> { Ok(0) }
+/// # fn part2(_: &util::Input) -> anyhow::Result<u32> { Ok(0) }
+/// util::check_fixtures!(part1, part2).unwrap();
+/// ```
+#[macro_export]
+macro_rules! check_fixtures {
+    ($part1:expr, $part2:expr $(,)?) => {
+        $crate::fixtures::check(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($crate::fixtures::DIRECTORY),
+            $part1,
+            $part2,
+        )
+    };
+}
+
+/// Get the path of the file with the expected answer to a part of the fixture at a path
+pub fn answer_path(fixture: &Path, part: u8) -> PathBuf {
+    let mut path = OsString::from(fixture);
+    path.push(format!(".part{}", part));
+    PathBuf::from(path)
+}
+
+/// Check if a file holds an expected answer rather than a fixture
+fn is_answer(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some("part1" | "part2")
+    )
+}
+
+/// Read a file that may be missing
+fn read_optional(path: &Path) -> std::io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Load all fixtures in a directory with their answers, ordered by name. A missing directory has
+/// no fixtures.
+pub fn load(directory: impl AsRef<Path>) -> std::io::Result<Vec<Fixture>> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+    let mut fixtures = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_file() && !is_answer(&path) {
+            fixtures.push(Fixture {
+                name: path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                input: Input::load(&path)?,
+                answers: [
+                    read_optional(&answer_path(&path, 1))?,
+                    read_optional(&answer_path(&path, 2))?,
+                ],
+            });
+        }
+    }
+    fixtures.sort_by(|first, second| first.name.cmp(&second.name));
+    Ok(fixtures)
+}
+
+/// Compare the answer of a part with the expected answer, if there is one
+fn compare(
+    fixture: &str,
+    part: u8,
+    answer: impl FnOnce() -> anyhow::Result<String>,
+    expected: &Option<String>,
+) -> anyhow::Result<()> {
+    let expected = match expected {
+        Some(expected) => expected,
+        None => return Ok(()),
+    };
+    let answer =
+        answer().with_context(|| format!("Part {} failed on fixture {}", part, fixture))?;
+    if answer != *expected {
+        bail!(
+            "Part {} answered {} for fixture {}, but {} is expected",
+            part,
+            answer,
+            fixture,
+            expected
+        );
+    }
+    Ok(())
+}
+
+/// Run both parts on every fixture in a directory, failing on the first answer that differs from
+/// the saved one. Parts without a saved answer aren't run.
+pub fn check<A: Display, B: Display>(
+    directory: impl AsRef<Path>,
+    part1: impl Fn(&Input) -> anyhow::Result<A>,
+    part2: impl Fn(&Input) -> anyhow::Result<B>,
+) -> anyhow::Result<()> {
+    for fixture in load(directory)? {
+        let [expected1, expected2] = &fixture.answers;
+        let answer1 = || part1(&fixture.input).map(|answer| answer.to_string());
+        compare(&fixture.name, 1, answer1, expected1)?;
+        let answer2 = || part2(&fixture.input).map(|answer| answer.to_string());
+        compare(&fixture.name, 2, answer2, expected2)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{answer_path, check, load};
+    use crate::Input;
+    use anyhow::{anyhow, Result};
+    use std::fs;
+
+    fn lines(input: &Input) -> Result<usize> {
+        Ok(input.as_lines().count())
+    }
+
+    fn numbers(input: &Input) -> Result<u32> {
+        input
+            .as_lines()
+            .map(|line| line.parse::<u32>().map_err(|_| anyhow!("Not a number")))
+            .sum()
+    }
+
+    #[test]
+    fn test_load() {
+        // given a directory with two fixtures, the answers to some of their parts and a
+        // subdirectory
+        let directory = std::env::temp_dir().join(format!("util-fixtures-{}", std::process::id()));
+        fs::create_dir_all(directory.join("nested")).unwrap();
+        fs::write(directory.join("larger"), "1\n2\n3").unwrap();
+        fs::write(directory.join("larger.part2"), "6").unwrap();
+        fs::write(directory.join("example1"), "1").unwrap();
+        fs::write(directory.join("example1.part1"), "1").unwrap();
+        fs::write(directory.join("example1.part2"), "1").unwrap();
+
+        // when loading them
+        let fixtures = load(&directory).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        // expect both fixtures to be loaded with their answers, ordered by name
+        let fixtures = fixtures
+            .iter()
+            .map(|fixture| {
+                let [part1, part2] = &fixture.answers;
+                let input = fixture.input.as_str();
+                (
+                    fixture.name.as_str(),
+                    input,
+                    part1.as_deref(),
+                    part2.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("example1", "1", Some("1"), Some("1")),
+                ("larger", "1\n2\n3", None, Some("6"))
+            ],
+            fixtures
+        );
+    }
+
+    #[test]
+    fn test_missing_directory() {
+        // expect a missing directory to have no fixtures
+        let directory = std::env::temp_dir().join("util-fixtures-missing");
+        assert!(load(&directory).unwrap().is_empty());
+        assert!(check(&directory, lines, numbers).is_ok());
+    }
+
+    #[test]
+    fn test_check() {
+        // given a fixture whose second part failed when it was saved
+        let directory = std::env::temp_dir().join(format!("util-check-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let fixture = directory.join("example1");
+        fs::write(&fixture, "1\nx").unwrap();
+        fs::write(answer_path(&fixture, 1), "2").unwrap();
+
+        // expect only the first part to be checked
+        let passed = check(&directory, lines, numbers);
+
+        // and a changed answer, or a part failing, to be reported
+        fs::write(answer_path(&fixture, 1), "3").unwrap();
+        let changed = check(&directory, lines, numbers);
+        fs::write(answer_path(&fixture, 2), "1").unwrap();
+        fs::write(answer_path(&fixture, 1), "2").unwrap();
+        let failed = check(&directory, lines, numbers);
+        fs::remove_dir_all(&directory).unwrap();
+        assert!(passed.is_ok(), "{:?}", passed);
+        assert_eq!(
+            "Part 1 answered 2 for fixture example1, but 3 is expected",
+            changed.unwrap_err().to_string()
+        );
+        assert_eq!(
+            "Part 2 failed on fixture example1",
+            failed.unwrap_err().to_string()
+        );
+    }
+}
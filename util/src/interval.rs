@@ -0,0 +1,311 @@
+//! Intervals of integers, like the section assignments of 2022 day 4, and sets of them.
+//!
+//! Intervals include both their start and their end, as most puzzles describe them that way.
+use std::ops::{Add, Sub};
+
+/// An integer type intervals can be made of
+pub trait Integer: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The integers from a start up to and including an end
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    /// Create an interval, if the start is not after the end
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// Get the number of integers in the interval, if it fits in the type of the interval
+    pub fn length(&self) -> Option<T> {
+        self.end.checked_sub(self.start)?.checked_add(T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Check if another interval lies completely inside this one
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Check if the intervals have any integer in common
+    pub fn intersects(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Get the integers the intervals have in common, if any
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.intersects(other).then(|| Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// Join the intervals, if they overlap or are next to each other
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        self.touches(other).then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// Check if the intervals overlap or are next to each other
+    fn touches(&self, other: &Interval<T>) -> bool {
+        self.intersects(other)
+            || (self.end < other.start && self.end + T::ONE == other.start)
+            || (other.end < self.start && other.end + T::ONE == self.start)
+    }
+}
+
+/// A set of integers, stored as the sorted intervals it is made of
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// Never overlapping or next to each other, those are merged
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Add an interval, merging it with the intervals it overlaps or is next to
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+        self.intervals.retain(|other| match merged.union(other) {
+            Some(union) => {
+                merged = union;
+                false
+            }
+            None => true,
+        });
+        let index = self
+            .intervals
+            .partition_point(|other| other.start < merged.start);
+        self.intervals.insert(index, merged);
+    }
+
+    /// Remove the integers of an interval from the set
+    pub fn subtract(&mut self, interval: &Interval<T>) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|other| {
+                if !other.intersects(interval) {
+                    return [Some(*other), None];
+                }
+                let before = (other.start < interval.start).then(|| Interval {
+                    start: other.start,
+                    end: interval.start - T::ONE,
+                });
+                let after = (interval.end < other.end).then(|| Interval {
+                    start: interval.end + T::ONE,
+                    end: other.end,
+                });
+                [before, after]
+            })
+            .flatten()
+            .collect();
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|other| other.end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|other| other.contains(value))
+    }
+
+    /// Get the number of integers in the set, if it fits in the type of the intervals
+    pub fn coverage(&self) -> Option<T> {
+        self.intervals.iter().try_fold(T::ZERO, |total, interval| {
+            total.checked_add(interval.length()?)
+        })
+    }
+
+    /// Iterate over the intervals of integers missing between the lowest and highest in the set
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals.windows(2).map(|pair| Interval {
+            start: pair[0].end + T::ONE,
+            end: pair[1].start - T::ONE,
+        })
+    }
+
+    /// Iterate over the intervals the set is made of, in order
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Get the number of separate intervals in the set
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Integer, Interval, IntervalSet};
+
+    fn interval<T: Integer>(start: T, end: T) -> Interval<T> {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_interval() {
+        // given two overlapping intervals
+        let left = interval(2, 6);
+        let right = interval(4, 8);
+
+        // expect them to be combined
+        assert_eq!(Some(5), left.length());
+        assert!(left.contains(6) && !left.contains(7));
+        assert!(left.intersects(&right));
+        assert_eq!(Some(interval(4, 6)), left.intersection(&right));
+        assert_eq!(Some(interval(2, 8)), left.union(&right));
+        assert!(!left.contains_interval(&right));
+        assert!(left.contains_interval(&interval(3, 5)));
+
+        // and intervals next to each other to have a union, but no intersection
+        let next = interval(7, 9);
+        assert_eq!(None, left.intersection(&next));
+        assert_eq!(Some(interval(2, 9)), left.union(&next));
+        assert_eq!(None, left.union(&interval(8, 9)));
+    }
+
+    #[test]
+    fn test_interval_at_limits() {
+        // given intervals ending at the limits of their type
+        let low = interval(u8::MIN, 10);
+        let high = interval(11, u8::MAX);
+
+        // expect them to be joined without overflowing
+        assert_eq!(Some(interval(0, 255)), low.union(&high));
+        assert_eq!(Some(interval(0, 255)), high.union(&low));
+    }
+
+    #[test]
+    fn test_invalid_interval() {
+        // expect an interval to only be created if it doesn't start after its end
+        assert_eq!(None, Interval::new(5, 4));
+        assert_eq!(None, Interval::new(u32::MAX, 0));
+        assert!(Interval::new(4, 4).is_some());
+    }
+
+    #[test]
+    fn test_length_at_limits() {
+        // given intervals covering (almost) all values of their type
+        let all = interval(0, u32::MAX);
+        let almost = interval(1, u32::MAX);
+        let signed = interval(i8::MIN, i8::MAX);
+
+        // expect only the lengths that fit in the type to be given
+        assert_eq!(None, all.length());
+        assert_eq!(Some(u32::MAX), almost.length());
+        assert_eq!(None, signed.length());
+        assert_eq!(Some(i8::MAX), interval(i8::MIN, -2).length());
+
+        // and the same for the coverage of sets
+        assert_eq!(None, IntervalSet::from_iter([all]).coverage());
+        let set = IntervalSet::from_iter([interval(0, 127), interval(129, u8::MAX)]);
+        assert_eq!(Some(255), set.coverage());
+        assert_eq!(vec![interval(128, 128)], set.gaps().collect::<Vec<_>>());
+        let set = IntervalSet::from_iter([interval(0, 127), interval(128, u8::MAX)]);
+        assert_eq!(None, set.coverage());
+    }
+
+    #[test]
+    fn test_merge() {
+        // given intervals that overlap, touch, and are apart
+        let set = [(10, 14), (1, 3), (12, 18), (4, 5), (20, 20)]
+            .into_iter()
+            .map(|(start, end)| interval(start, end))
+            .collect::<IntervalSet<i32>>();
+
+        // expect them to be merged
+        assert_eq!(
+            vec![&interval(1, 5), &interval(10, 18), &interval(20, 20)],
+            set.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(15), set.coverage());
+        assert_eq!(
+            vec![interval(6, 9), interval(19, 19)],
+            set.gaps().collect::<Vec<_>>()
+        );
+        assert!(set.contains(12) && set.contains(20));
+        assert!(!set.contains(19) && !set.contains(0));
+    }
+
+    #[test]
+    fn test_subtract() {
+        // given a set of two intervals
+        let mut set = IntervalSet::from_iter([interval(0, 10), interval(20, 30)]);
+
+        // when cutting a hole in one and the end off the other
+        set.subtract(&interval(4, 6));
+        set.subtract(&interval(25, 40));
+
+        // expect three intervals to be left
+        assert_eq!(
+            vec![&interval(0, 3), &interval(7, 10), &interval(20, 24)],
+            set.iter().collect::<Vec<_>>()
+        );
+
+        // and subtracting everything to leave nothing
+        set.subtract(&interval(0, 30));
+        assert!(set.is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+//! Extraction of the integers in text, skipping whatever is around them.
+//!
+//! A `-` is taken as the sign of a signed integer, unless it follows a letter or digit, so
+//! `x=-3` holds -3 while ranges like `2-4` hold 2 and 4:
+//!
+//! ```
+//! let [count, from, to] = util::ints::ints_array::<usize, 3>("move 1 from 2 to 1").unwrap();
+//! assert_eq!((1, 2, 1), (count, from, to));
+//! assert_eq!(vec![2, -3], util::ints::ints::<i64>("x=2, y=-3").unwrap());
+//! ```
+use anyhow::{anyhow, Result};
+use std::str::FromStr;
+
+/// An integer type that can be extracted from text
+pub trait Int: FromStr + Copy + 'static {
+    /// Whether a `-` in front of the digits belongs to the integer
+    const SIGNED: bool;
+}
+
+macro_rules! impl_int {
+    ($signed:literal: $($t:ty),*) => {
+        $(impl Int for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+impl_int!(true: i8, i16, i32, i64, i128, isize);
+impl_int!(false: u8, u16, u32, u64, u128, usize);
+
+/// Get the integers in a text, in order
+pub fn ints<T: Int>(text: &str) -> Result<Vec<T>> {
+    let bytes = text.as_bytes();
+    let mut ints = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+        let mut start = index;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if T::SIGNED
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        let int = &text[start..index];
+        ints.push(T::from_str(int).map_err(|_| anyhow!("Invalid integer {}", int))?);
+    }
+    Ok(ints)
+}
+
+/// Get the integers in a text that has exactly `N` of them
+pub fn ints_array<T: Int, const N: usize>(text: &str) -> Result<[T; N]> {
+    let ints = ints(text)?;
+    let count = ints.len();
+    ints.try_into()
+        .map_err(|_| anyhow!("Expected {} integers in {}, found {}", N, text, count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ints, ints_array};
+
+    #[test]
+    fn test_ints() {
+        // given text with integers mixed with prose
+        let text = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+
+        // expect the integers to be extracted with their signs
+        assert_eq!(vec![2, -18, -2, 15], ints::<i64>(text).unwrap());
+
+        // and to lose their signs when extracted as unsigned
+        assert_eq!(vec![2, 18, 2, 15], ints::<u32>(text).unwrap());
+    }
+
+    #[test]
+    fn test_dashes() {
+        // expect dashes between integers to not be signs, unless another dash comes first
+        assert_eq!(vec![2, 4, 6, 8], ints::<i32>("2-4,6-8").unwrap());
+        assert_eq!(vec![-11], ints::<i32>("addx -11").unwrap());
+        assert_eq!(vec![-1, -2], ints::<i32>("-1--2").unwrap());
+        assert_eq!(vec![3], ints::<i32>("a-3").unwrap());
+        assert!(ints::<i32>("no numbers - here").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        // expect integers that don't fit to be an error
+        assert!(ints::<u8>("256").is_err());
+        assert_eq!(vec![255], ints::<u8>("255").unwrap());
+    }
+
+    #[test]
+    fn test_ints_array() {
+        // expect exactly the asked number of integers to be extracted
+        assert_eq!(
+            [1, 2, 1],
+            ints_array::<usize, 3>("move 1 from 2 to 1").unwrap()
+        );
+        assert!(ints_array::<usize, 2>("move 1 from 2 to 1").is_err());
+        assert!(ints_array::<usize, 4>("move 1 from 2 to 1").is_err());
+    }
+}
@@ -1,8 +1,30 @@
+use std::fmt::Display;
 use std::fs::File;
-use std::io::Read;
+use std::io::{ErrorKind, Read};
 use std::path::Path;
 use std::str::Split;
 
+pub mod alloc;
+pub mod bitset;
+pub mod columns;
+pub mod cycle;
+pub mod dsu;
+pub mod encryption;
+pub mod fixtures;
+pub mod interval;
+pub mod ints;
+pub mod memo;
+pub mod parse;
+pub mod registry;
+pub mod search;
+pub mod snapshot;
+pub mod sparse;
+pub mod trace;
+
+use encryption::Key;
+use ints::Int;
+use parse::{ParseError, Parser};
+
 #[derive(Clone)]
 pub struct Input(String);
 
@@ -30,11 +52,41 @@ impl Input {
     }
 }
 
+impl From<String> for Input {
+    /// Use a string as input as is, without trimming it
+    fn from(input: String) -> Input {
+        Input(input)
+    }
+}
+
 impl Input {
+    /// Load an input from a file, or decrypt it from the encrypted file next to it if there is
+    /// no plain file
     pub fn load(path: impl AsRef<Path>) -> std::io::Result<Input> {
+        let path = path.as_ref();
         let mut input = String::new();
-        File::open(path)?.read_to_string(&mut input)?;
-        Ok(Input(input))
+        match File::open(path) {
+            Ok(mut file) => {
+                file.read_to_string(&mut input)?;
+                Ok(Input(input))
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                let encrypted = encryption::encrypted_path(path);
+                if !encrypted.exists() {
+                    return Err(error);
+                }
+                Input::load_encrypted(encrypted, &Key::load()?)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Load an input from an encrypted file
+    pub fn load_encrypted(path: impl AsRef<Path>, key: &Key) -> std::io::Result<Input> {
+        let input = key.decrypt(&std::fs::read(path)?)?;
+        String::from_utf8(input)
+            .map(Input)
+            .map_err(|error| std::io::Error::new(ErrorKind::InvalidData, error))
     }
 
     /// Get the input as a string
@@ -43,14 +95,68 @@ impl Input {
     }
 
     /// Get the input as an interator of lines
-    pub fn as_lines(&self) -> Split<char> {
+    pub fn as_lines(&self) -> Split<'_, char> {
         self.0.split('\n')
     }
+
+    /// Get the lines without a line for trailing newlines, split the same way as
+    /// [`as_lines`](Input::as_lines) so all helpers see the same lines
+    fn trimmed_lines(&self) -> Split<'_, char> {
+        self.0.trim_end_matches('\n').split('\n')
+    }
+
+    /// Get all integers in the input, in order
+    pub fn ints<T: Int>(&self) -> anyhow::Result<Vec<T>> {
+        ints::ints(&self.0)
+    }
+
+    /// Get the integers in every line, without a line for trailing newlines
+    pub fn line_ints<T: Int>(&self) -> impl Iterator<Item = anyhow::Result<Vec<T>>> + '_ {
+        self.trimmed_lines().map(ints::ints)
+    }
+
+    /// Parse every line with a parser, which has to parse the whole line. Errors are located by
+    /// their line in the input.
+    pub fn parse_lines<T>(&self, parser: &impl Parser<T>) -> Result<Vec<T>, ParseError> {
+        self.trimmed_lines()
+            .enumerate()
+            .map(|(index, line)| {
+                parse::parse_all(parser, line).map_err(|error| ParseError {
+                    line: index + 1,
+                    ..error
+                })
+            })
+            .collect()
+    }
+
+    /// Get the integers in every line, which must have exactly `N` of them
+    pub fn line_ints_array<T: Int, const N: usize>(
+        &self,
+    ) -> impl Iterator<Item = anyhow::Result<[T; N]>> + '_ {
+        self.trimmed_lines().map(ints::ints_array)
+    }
+}
+
+/// Load the input for a day and print the answers to both parts
+pub fn run<A: Display, B: Display>(
+    path: impl AsRef<Path>,
+    part1: impl Fn(&Input) -> anyhow::Result<A>,
+    part2: impl Fn(&Input) -> anyhow::Result<B>,
+) -> anyhow::Result<()> {
+    let input = Input::load(path)?;
+
+    println!("Part 1:");
+    println!("{}", part1(&input)?);
+
+    println!("Part 2:");
+    println!("{}", part2(&input)?);
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Input;
+    use super::parse::{literal, number, Parser};
+    use super::{Input, Key};
 
     #[test]
     fn test_as_str() {
@@ -82,6 +188,87 @@ mod tests {
         assert_eq!("a line\nanother line", input.as_str());
     }
 
+    #[test]
+    fn test_from_string() {
+        // given a string with a trailing newline
+        let input = "a line\nanother line\n".to_string();
+
+        // when Input is created from the string
+        let input = Input::from(input);
+
+        // then the string is kept as is
+        assert_eq!("a line\nanother line\n", input.as_str());
+    }
+
+    #[test]
+    fn test_load_encrypted() {
+        // given an encrypted input, without a plain input next to it
+        let key = Key::generate();
+        let path = std::env::temp_dir().join(format!("util-input-{}.age", std::process::id()));
+        std::fs::write(&path, key.encrypt(b"a line\nanother line\n").unwrap()).unwrap();
+
+        // expect it to be decrypted with the key it was encrypted with
+        let input = Input::load_encrypted(&path, &key);
+        let other = Input::load_encrypted(&path, &Key::generate());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!("a line\nanother line\n", input.unwrap().as_str());
+        assert!(other.is_err());
+    }
+
+    #[test]
+    fn test_ints() {
+        // given some lines with integers
+        let input = Input("x=1, y=-2\nx=-3, y=4\n\n".to_string());
+
+        // expect the integers to be extracted for the whole input and per line
+        assert_eq!(vec![1, -2, -3, 4], input.ints::<i64>().unwrap());
+        assert_eq!(
+            vec![vec![1, -2], vec![-3, 4]],
+            input
+                .line_ints::<i64>()
+                .collect::<anyhow::Result<Vec<_>>>()
+                .unwrap()
+        );
+        assert_eq!(
+            vec![[1, 2], [3, 4]],
+            input
+                .line_ints_array::<u8, 2>()
+                .collect::<anyhow::Result<Vec<_>>>()
+                .unwrap()
+        );
+        assert!(input.line_ints_array::<u8, 3>().all(|ints| ints.is_err()));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        // given lines of instructions, and a parser for them
+        let input = Input("addx 3\naddx -5\nnoop\n".to_string());
+        let instruction = literal("addx ")
+            .ignore_then(number::<i32>())
+            .or(literal("noop").map(|_| 0));
+
+        // expect every line to be parsed
+        assert_eq!(Ok(vec![3, -5, 0]), input.parse_lines(&instruction));
+
+        // and errors to be located on the line they are on
+        let input = Input("noop\nnoop\naddx x\n".to_string());
+        let error = input.parse_lines(&instruction).unwrap_err();
+        assert_eq!((3, 6), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_same_lines() {
+        // given input with a carriage return and trailing newlines
+        let input = Input("12\n3\r\n\n".to_string());
+
+        // expect every helper to see the same two lines, keeping the carriage return
+        itertools::assert_equal(input.trim_trailing_newlines().as_lines(), ["12", "3\r"]);
+        let ints = input.line_ints::<u32>().collect::<anyhow::Result<Vec<_>>>();
+        assert_eq!(vec![vec![12], vec![3]], ints.unwrap());
+        let error = input.parse_lines(&number::<u32>()).unwrap_err();
+        assert_eq!((2, 2), (error.line, error.column));
+    }
+
     #[test]
     fn test_trim_trailing_newlines() {
         // given some input with trailing newlines
@@ -0,0 +1,209 @@
+//! Caching the results of pure recursive functions, keyed by their arguments.
+//!
+//! The memo is passed along to the recursive calls:
+//!
+//! ```
+//! use util::memo::Memo;
+//!
+//! fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+//!     memo.get_or_compute(n, |memo| match n {
+//!         0 | 1 => n,
+//!         n => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+//!     })
+//! }
+//!
+//! let mut memo = Memo::new("fibonacci");
+//! assert_eq!(12586269025, fibonacci(&mut memo, 50));
+//! ```
+//!
+//! When a memo is dropped it emits its statistics as an info event, which the runner shows with
+//! `--trace info`.
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
+/// How well a memo did
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of results found in the cache
+    pub hits: usize,
+    /// The number of results that had to be computed
+    pub misses: usize,
+    /// The number of results removed to stay within the capacity
+    pub evictions: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let calls = self.hits + self.misses;
+        let rate = if calls == 0 {
+            0.0
+        } else {
+            self.hits as f64 * 100.0 / calls as f64
+        };
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} evictions",
+            self.hits, self.misses, rate, self.evictions
+        )
+    }
+}
+
+/// A cache of the results of a function
+pub struct Memo<K, V> {
+    name: &'static str,
+    cache: HashMap<K, V>,
+    /// The most results to keep, or `None` to keep every result
+    capacity: Option<usize>,
+    /// The keys in the order they were cached, to evict the oldest first when bounded
+    order: VecDeque<K>,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    /// Create a memo keeping every result, with a name to report its statistics under
+    pub fn new(name: &'static str) -> Memo<K, V> {
+        Memo {
+            name,
+            cache: HashMap::new(),
+            capacity: None,
+            order: VecDeque::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Create a memo keeping at most `capacity` results, forgetting the oldest ones first
+    pub fn bounded(name: &'static str, capacity: usize) -> Memo<K, V> {
+        assert!(capacity > 0, "A memo has to keep at least one result");
+        let mut memo = Memo::new(name);
+        memo.capacity = Some(capacity);
+        memo
+    }
+
+    /// Get the cached result for a key, or compute and cache it. The computation gets the memo
+    /// to make its recursive calls with.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = compute(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if let Some(capacity) = self.capacity {
+            // A recursive call may have cached the same key while computing it
+            if !self.cache.contains_key(&key) {
+                while self.cache.len() >= capacity {
+                    let Some(oldest) = self.order.pop_front() else {
+                        break;
+                    };
+                    self.cache.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+                self.order.push_back(key.clone());
+            }
+        }
+        self.cache.insert(key, value);
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Get the number of cached results
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        let Stats {
+            hits,
+            misses,
+            evictions,
+        } = self.stats;
+        if hits + misses > 0 {
+            tracing::info!(
+                memo = self.name,
+                hits,
+                misses,
+                evictions,
+                size = self.cache.len(),
+                "{}",
+                self.stats
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Memo, Stats};
+
+    /// Count the ways to climb stairs taking 1 or 2 steps at a time
+    fn climb(memo: &mut Memo<u64, u64>, stairs: u64) -> u64 {
+        memo.get_or_compute(stairs, |memo| match stairs {
+            0 | 1 => 1,
+            _ => climb(memo, stairs - 1) + climb(memo, stairs - 2),
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        // given a memo
+        let mut memo = Memo::new("climb");
+
+        // when climbing stairs of 30 steps
+        let ways = climb(&mut memo, 30);
+
+        // expect every number of stairs to be computed once
+        assert_eq!(1346269, ways);
+        assert_eq!(31, memo.len());
+        assert_eq!(
+            Stats {
+                hits: 28,
+                misses: 31,
+                evictions: 0
+            },
+            memo.stats()
+        );
+
+        // and to be found when climbing them again
+        climb(&mut memo, 30);
+        assert_eq!(29, memo.stats().hits);
+    }
+
+    #[test]
+    fn test_bounded() {
+        // given a memo that keeps 3 results, the most climbing needs
+        let mut memo = Memo::bounded("climb", 3);
+
+        // expect the right result, while computing everything once and keeping only 3 results
+        assert_eq!(1346269, climb(&mut memo, 30));
+        assert_eq!(3, memo.len());
+        assert_eq!(31, memo.stats().misses);
+        assert_eq!(28, memo.stats().evictions);
+    }
+
+    #[test]
+    fn test_display_stats() {
+        let stats = Stats {
+            hits: 3,
+            misses: 1,
+            evictions: 0,
+        };
+        assert_eq!(
+            "3 hits, 1 misses (75.0% hit rate), 0 evictions",
+            stats.to_string()
+        );
+    }
+}
@@ -0,0 +1,383 @@
+//! Parser combinators for puzzle formats with nested or recursive syntax.
+//!
+//! A parser takes a `&str` and returns what it parsed with the rest of the text. Functions with
+//! that signature are parsers too, so recursive formats are parsed by calling a function from
+//! within itself, like the packets of 2022 day 13:
+//!
+//! ```
+//! use util::parse::{self, literal, number, separated, Parser, PResult};
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum Packet {
+//!     Integer(u32),
+//!     List(Vec<Packet>),
+//! }
+//!
+//! fn packet(input: &str) -> PResult<'_, Packet> {
+//!     number()
+//!         .map(Packet::Integer)
+//!         .or(separated(packet, literal(","))
+//!             .delimited_by(literal("["), literal("]"))
+//!             .map(Packet::List))
+//!         .parse(input)
+//! }
+//!
+//! let parsed = parse::parse_all(&packet, "[1,[2]]").unwrap();
+//! assert_eq!(
+//!     Packet::List(vec![Packet::Integer(1), Packet::List(vec![Packet::Integer(2)])]),
+//!     parsed
+//! );
+//! let error = parse::parse_all(&packet, "[1,[2}]").unwrap_err();
+//! assert_eq!("Expected \"]\" at line 1, column 6, found '}'", error.to_string());
+//! ```
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::ints::Int;
+
+/// The result of parsing the start of a text: the parsed value and the rest of the text
+pub type PResult<'a, T> = Result<(T, &'a str), Failure>;
+
+/// Where parsing failed, and what was expected there
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    /// The length of the text that was left, which locates the failure in the complete text
+    remaining: usize,
+    expected: Vec<String>,
+}
+
+impl Failure {
+    /// Fail at the start of the remaining text
+    pub fn new(input: &str, expected: impl Into<String>) -> Failure {
+        Failure {
+            remaining: input.len(),
+            expected: vec![expected.into()],
+        }
+    }
+
+    /// Combine two failures of alternatives, keeping the one that got furthest
+    fn merge(mut self, mut other: Failure) -> Failure {
+        if self.remaining < other.remaining {
+            return self;
+        }
+        if other.remaining < self.remaining {
+            return other;
+        }
+        other
+            .expected
+            .retain(|expected| !self.expected.contains(expected));
+        self.expected.append(&mut other.expected);
+        self
+    }
+
+    /// Check if the failure happened without parsing anything of a text
+    fn at_start_of(&self, input: &str) -> bool {
+        self.remaining == input.len()
+    }
+}
+
+/// A failure to parse a text, located by line and column
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: Vec<String>,
+    /// The character that was found instead, or `None` at the end of the text
+    pub found: Option<char>,
+}
+
+impl ParseError {
+    /// Locate a failure in the text that was parsed
+    pub fn new(text: &str, failure: Failure) -> ParseError {
+        let offset = text.len() - failure.remaining;
+        let parsed = &text[..offset];
+        let line_start = parsed.rfind('\n').map_or(0, |index| index + 1);
+        ParseError {
+            line: parsed.matches('\n').count() + 1,
+            column: parsed[line_start..].chars().count() + 1,
+            expected: failure.expected,
+            found: text[offset..].chars().next(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected {} at line {}, column {}, found ",
+            self.expected.join(" or "),
+            self.line,
+            self.column
+        )?;
+        match self.found {
+            Some(c) => write!(f, "{:?}", c),
+            None => write!(f, "the end"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Something that parses a value from the start of a text
+pub trait Parser<T> {
+    fn parse<'a>(&self, input: &'a str) -> PResult<'a, T>;
+
+    /// Turn the parsed value into another value
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<U>
+    where
+        Self: Sized,
+    {
+        from_fn(move |input| {
+            let (value, rest) = self.parse(input)?;
+            Ok((f(value), rest))
+        })
+    }
+
+    /// Parse something else after this, keeping both values
+    fn then<U>(self, next: impl Parser<U>) -> impl Parser<(T, U)>
+    where
+        Self: Sized,
+    {
+        from_fn(move |input| {
+            let (first, rest) = self.parse(input)?;
+            let (second, rest) = next.parse(rest)?;
+            Ok(((first, second), rest))
+        })
+    }
+
+    /// Parse something else after this, keeping only this value
+    fn then_ignore<U>(self, next: impl Parser<U>) -> impl Parser<T>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(value, _)| value)
+    }
+
+    /// Parse something else after this, keeping only the other value
+    fn ignore_then<U>(self, next: impl Parser<U>) -> impl Parser<U>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(_, value)| value)
+    }
+
+    /// Parse something between an opening and a closing parser, like brackets
+    fn delimited_by<O, C>(self, open: impl Parser<O>, close: impl Parser<C>) -> impl Parser<T>
+    where
+        Self: Sized,
+    {
+        open.ignore_then(self).then_ignore(close)
+    }
+
+    /// Try another parser if this one fails
+    fn or(self, other: impl Parser<T>) -> impl Parser<T>
+    where
+        Self: Sized,
+    {
+        from_fn(move |input| {
+            self.parse(input).or_else(|failure| {
+                other
+                    .parse(input)
+                    .map_err(|other_failure| failure.merge(other_failure))
+            })
+        })
+    }
+}
+
+impl<T, F> Parser<T> for F
+where
+    F: for<'a> Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse<'a>(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Let the compiler know a closure is a parser for texts of any lifetime
+fn from_fn<T, F>(f: F) -> F
+where
+    F: for<'a> Fn(&'a str) -> PResult<'a, T>,
+{
+    f
+}
+
+/// Parse a literal text
+pub fn literal(expected: &'static str) -> impl Parser<&'static str> {
+    from_fn(move |input| match input.strip_prefix(expected) {
+        Some(rest) => Ok((expected, rest)),
+        None => Err(Failure::new(input, format!("{:?}", expected))),
+    })
+}
+
+/// Parse an integer, with a `-` in front of it if it is signed
+pub fn number<T: Int>() -> impl Parser<T> {
+    from_fn(|input| {
+        let sign = usize::from(T::SIGNED && input.starts_with('-'));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+        if digits == 0 {
+            return Err(Failure::new(input, "a number"));
+        }
+        let (number, rest) = input.split_at(sign + digits);
+        let number = number
+            .parse()
+            .map_err(|_| Failure::new(input, "a smaller number"))?;
+        Ok((number, rest))
+    })
+}
+
+/// Skip any whitespace, including newlines
+pub fn whitespace() -> impl Parser<()> {
+    from_fn(|input| Ok(((), input.trim_start())))
+}
+
+/// Parse something with optional whitespace around it
+pub fn padded<T>(parser: impl Parser<T>) -> impl Parser<T> {
+    parser.delimited_by(whitespace(), whitespace())
+}
+
+/// Parse something as many times as possible, possibly zero times
+pub fn many<T>(parser: impl Parser<T>) -> impl Parser<Vec<T>> {
+    from_fn(move |mut input| {
+        let mut values = Vec::new();
+        loop {
+            match parser.parse(input) {
+                // Stop at parsers that succeed without parsing anything, they would never stop
+                Ok((value, rest)) if rest.len() < input.len() => {
+                    values.push(value);
+                    input = rest;
+                }
+                Ok(_) => return Ok((values, input)),
+                Err(failure) if failure.at_start_of(input) => return Ok((values, input)),
+                Err(failure) => return Err(failure),
+            }
+        }
+    })
+}
+
+/// Parse a list of things with separators between them, possibly an empty list
+pub fn separated<T, S>(parser: impl Parser<T>, separator: impl Parser<S>) -> impl Parser<Vec<T>> {
+    from_fn(move |input| {
+        let (first, mut input) = match parser.parse(input) {
+            Ok(parsed) => parsed,
+            Err(failure) if failure.at_start_of(input) => return Ok((Vec::new(), input)),
+            Err(failure) => return Err(failure),
+        };
+        let mut values = vec![first];
+        loop {
+            let rest = match separator.parse(input) {
+                Ok((_, rest)) => rest,
+                Err(failure) if failure.at_start_of(input) => return Ok((values, input)),
+                Err(failure) => return Err(failure),
+            };
+            let (value, rest) = parser.parse(rest)?;
+            values.push(value);
+            input = rest;
+        }
+    })
+}
+
+/// Parse a complete text, failing if anything is left after parsing
+pub fn parse_all<T>(parser: &impl Parser<T>, text: &str) -> Result<T, ParseError> {
+    parser
+        .parse(text)
+        .and_then(|(value, rest)| match rest.is_empty() {
+            true => Ok(value),
+            false => Err(Failure::new(rest, "the end")),
+        })
+        .map_err(|failure| ParseError::new(text, failure))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{literal, many, number, padded, parse_all, separated, whitespace, Parser};
+
+    #[test]
+    fn test_number() {
+        assert_eq!(Ok(-12), parse_all(&number::<i32>(), "-12"));
+        assert_eq!(Ok(12), parse_all(&number::<u32>(), "12"));
+        assert!(parse_all(&number::<u32>(), "-12").is_err());
+        assert!(parse_all(&number::<u8>(), "256").is_err());
+        assert!(parse_all(&number::<i8>(), "-").is_err());
+    }
+
+    /// Parse a line of prose with numbers
+    fn crane_move() -> impl Parser<[usize; 3]> {
+        literal("move ")
+            .ignore_then(number())
+            .then_ignore(literal(" from "))
+            .then(number())
+            .then_ignore(literal(" to "))
+            .then(number())
+            .map(|((count, from), to)| [count, from, to])
+    }
+
+    #[test]
+    fn test_instruction() {
+        // expect the numbers of a line to be parsed
+        assert_eq!(
+            Ok([1, 2, 3]),
+            parse_all(&crane_move(), "move 1 from 2 to 3")
+        );
+
+        // and a missing number to be pointed out
+        let error = parse_all(&crane_move(), "move 1 from x to 3").unwrap_err();
+        assert_eq!((1, 13), (error.line, error.column));
+        assert_eq!(vec!["a number"], error.expected);
+        assert_eq!(Some('x'), error.found);
+    }
+
+    /// Parse lists of numbers between parentheses
+    fn lists() -> impl Parser<Vec<Vec<i64>>> {
+        let list =
+            separated(padded(number()), literal(",")).delimited_by(literal("("), literal(")"));
+        padded(many(padded(list)))
+    }
+
+    #[test]
+    fn test_lists() {
+        // expect lists spread over lines to be parsed
+        assert_eq!(
+            Ok(vec![vec![1, -2], vec![], vec![3]]),
+            parse_all(&lists(), " (1, -2)\n()\n( 3 )\n")
+        );
+
+        // and errors to be located on their line
+        let error = parse_all(&lists(), "(1)\n(2,\n3,)").unwrap_err();
+        assert_eq!((3, 3), (error.line, error.column));
+        assert_eq!(
+            "Expected a number at line 3, column 3, found ')'",
+            error.to_string()
+        );
+    }
+
+    /// Parse one of two instructions
+    fn instruction() -> impl Parser<i32> {
+        literal("addx ")
+            .ignore_then(number())
+            .or(literal("noop").map(|_| 0))
+    }
+
+    #[test]
+    fn test_or() {
+        // expect both alternatives to be parsed
+        assert_eq!(Ok(-3), parse_all(&instruction(), "addx -3"));
+        assert_eq!(Ok(0), parse_all(&instruction(), "noop"));
+
+        // and the failure of the one that got furthest to be reported
+        let error = parse_all(&instruction(), "addx ?").unwrap_err();
+        assert_eq!(vec!["a number"], error.expected);
+        let error = parse_all(&instruction(), "nop").unwrap_err();
+        assert_eq!(vec!["\"addx \"", "\"noop\""], error.expected);
+
+        // and anything left over to be an error
+        let error = parse_all(&instruction(), "noop ").unwrap_err();
+        assert_eq!(
+            "Expected the end at line 1, column 5, found ' '",
+            error.to_string()
+        );
+        assert_eq!(Ok(((), "")), whitespace().parse(" \n "));
+    }
+}
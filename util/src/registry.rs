@@ -0,0 +1,138 @@
+//! Registry of all solutions linked into a binary
+//!
+//! Every day registers its solution with [`register!`](crate::register), which adds it to a
+//! distributed slice that is assembled by the linker, so the runner finds all days without
+//! having to list them anywhere.
+use crate::Input;
+
+pub use linkme;
+
+/// A part of a solution, returning the answer formatted for display
+pub type Part = fn(&Input) -> anyhow::Result<String>;
+
+/// A registered solution for one day of the calendar
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The first part, if it has been implemented
+    pub part1: Option<Part>,
+    /// The second part, if it has been implemented
+    pub part2: Option<Part>,
+}
+
+#[linkme::distributed_slice]
+pub static SOLUTIONS: [Solution];
+
+/// Get all registered solutions, ordered by year and day
+pub fn solutions() -> Vec<&'static Solution> {
+    let mut solutions = SOLUTIONS.iter().collect::<Vec<_>>();
+    solutions.sort_by_key(|solution| (solution.year, solution.day));
+    solutions
+}
+
+/// Get all years with registered solutions, in order
+pub fn years() -> Vec<u16> {
+    let mut years = SOLUTIONS
+        .iter()
+        .map(|solution| solution.year)
+        .collect::<Vec<_>>();
+    years.sort_unstable();
+    years.dedup();
+    years
+}
+
+/// Find the registered solution for a day
+pub fn find(year: u16, day: u8) -> Option<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
+
+/// Register the solution for a day, leaving out the parts that haven't been implemented yet
+///
+/// ```ignore
+/// util::register! {
+///     year: 2022,
+///     day: 1,
+///     title: "Calorie Counting",
+///     part1: part1,
+///     part2: part2,
+/// }
+/// ```
+#[macro_export]
+macro_rules! register {
+    (@part) => {
+        None
+    };
+    (@part $part:path) => {
+        Some(|input: &$crate::Input| $part(input).map(|answer| answer.to_string()))
+    };
+    (
+        year: $year:expr,
+        day: $day:expr,
+        title: $title:expr
+        $(, part1: $part1:path)?
+        $(, part2: $part2:path)?
+        $(,)?
+    ) => {
+        #[$crate::registry::linkme::distributed_slice($crate::registry::SOLUTIONS)]
+        #[linkme(crate = $crate::registry::linkme)]
+        static SOLUTION: $crate::registry::Solution = $crate::registry::Solution {
+            year: $year,
+            day: $day,
+            title: $title,
+            part1: $crate::register!(@part $($part1)?),
+            part2: $crate::register!(@part $($part2)?),
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, solutions, years};
+    use crate::Input;
+
+    fn part1(input: &Input) -> anyhow::Result<usize> {
+        Ok(input.as_lines().count())
+    }
+
+    crate::register! {
+        year: 2015,
+        day: 1,
+        title: "Test",
+        part1: part1,
+    }
+
+    #[test]
+    fn test_find() {
+        // given a registered solution
+        let solution = find(2015, 1).unwrap();
+
+        // expect its metadata and implemented parts to be available
+        assert_eq!("Test", solution.title);
+        assert!(solution.part2.is_none());
+        let answer = (solution.part1.unwrap())(&Input::from_lines(["a", "b"])).unwrap();
+        assert_eq!("2", answer);
+    }
+
+    #[test]
+    fn test_find_missing() {
+        // expect days without a registered solution not to be found
+        assert!(find(2015, 2).is_none());
+    }
+
+    #[test]
+    fn test_years() {
+        // expect the year of the registered solution to be listed
+        assert!(years().contains(&2015));
+    }
+
+    #[test]
+    fn test_solutions() {
+        // expect the registered solution to be listed
+        assert!(solutions()
+            .iter()
+            .any(|solution| solution.year == 2015 && solution.day == 1));
+    }
+}
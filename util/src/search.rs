@@ -0,0 +1,357 @@
+//! Searching graphs for the shortest paths between nodes.
+//!
+//! Graphs are given by a function returning the neighbours of a node, so they don't need to be
+//! built up front. [`grid_bfs`] walks a grid of cells stored row by row, stepping up, down, left
+//! or right to the cells a predicate allows, such as a hill climbed one step up at a time.
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The shortest paths found from the start of a search to every node it reached
+pub struct Paths<N, C> {
+    costs: HashMap<N, C>,
+    previous: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new() -> Paths<N, C> {
+        Paths {
+            costs: HashMap::new(),
+            previous: HashMap::new(),
+        }
+    }
+
+    /// Get the cost of the shortest path to a node, if it was reached
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Get the shortest path to a node, from the start it was reached from up to and including
+    /// the node itself
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.previous.get(&path[path.len() - 1]) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Iterate over all reached nodes and the costs of the shortest paths to them
+    pub fn reached(&self) -> impl Iterator<Item = (&N, &C)> {
+        self.costs.iter()
+    }
+}
+
+/// Find the shortest paths, in number of steps, from any of the starts to every reachable node
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.costs.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let steps = paths.costs[&node] + 1;
+        for neighbour in neighbours(&node) {
+            if let Entry::Vacant(entry) = paths.costs.entry(neighbour.clone()) {
+                entry.insert(steps);
+                paths.previous.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    paths
+}
+
+/// A node waiting to be visited, ordered so the cheapest one is popped first from a max-heap
+struct Queued<N, C> {
+    /// The cost to get here plus the estimated cost to the goal, if there is one
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Find the cheapest paths from any of the starts to every reachable node, where the neighbours
+/// of a node come with the cost of stepping to them, which must not be negative
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    cheapest_paths(starts, neighbours, |_| C::default(), |_| false)
+}
+
+/// Find the cheapest path from the start to a goal, where the neighbours of a node come with the
+/// cost of stepping to them and the heuristic estimates the cost from a node to the closest goal.
+///
+/// The path is only guaranteed to be the cheapest if the heuristic never overestimates.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut goal = None;
+    let paths = cheapest_paths([start], neighbours, heuristic, |node| {
+        if is_goal(node) {
+            goal = Some(node.clone());
+        }
+        goal.is_some()
+    });
+    let goal = goal?;
+    Some((paths.path(&goal)?, paths.cost(&goal)?))
+}
+
+/// Dijkstra's algorithm guided by a heuristic, which makes it A*, stopping once `stop` returns true
+/// for a visited node
+fn cheapest_paths<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut stop: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        paths.costs.insert(start.clone(), C::default());
+        queue.push(Queued {
+            priority: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        });
+    }
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // Nodes are queued again when a cheaper path is found, skip the outdated entries
+        if paths.costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if stop(&node) {
+            break;
+        }
+        for (neighbour, step) in neighbours(&node) {
+            let cost = cost + step;
+            if paths.costs.get(&neighbour).is_none_or(|&best| cost < best) {
+                paths.costs.insert(neighbour.clone(), cost);
+                paths.previous.insert(neighbour.clone(), node.clone());
+                queue.push(Queued {
+                    priority: cost + heuristic(&neighbour),
+                    cost,
+                    node: neighbour,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// A position in a grid, as x and y
+pub type Position = (usize, usize);
+
+/// Get the positions up, down, left and right of a position that are inside a grid
+pub fn grid_neighbours(
+    (x, y): Position,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = Position> {
+    [
+        (y > 0).then(|| (x, y - 1)),
+        (y + 1 < height).then_some((x, y + 1)),
+        (x > 0).then(|| (x - 1, y)),
+        (x + 1 < width).then_some((x + 1, y)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Find the shortest paths from any of the starts to every reachable position in a grid of cells
+/// stored row by row, where `passable(from, to)` decides if a step between two cells is allowed.
+/// Starts outside the grid are skipped.
+pub fn grid_bfs<T>(
+    cells: &[T],
+    width: usize,
+    starts: impl IntoIterator<Item = Position>,
+    passable: impl Fn(&T, &T) -> bool,
+) -> Paths<Position, usize> {
+    let height = cells.len().checked_div(width).unwrap_or(0);
+    let starts = starts.into_iter().filter(|&(x, y)| x < width && y < height);
+    bfs(starts, |&(x, y)| {
+        let from = &cells[y * width + x];
+        grid_neighbours((x, y), width, height)
+            .filter(|&(to_x, to_y)| passable(from, &cells[to_y * width + to_x]))
+            .collect::<Vec<_>>()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{astar, bfs, dijkstra, grid_bfs, grid_neighbours, Position};
+
+    /// The hill from 2022 day 12, which is climbed one step up at a time
+    const HILL: [&str; 5] = ["Sabqponm", "abcryxxl", "accszExk", "acctuvwj", "abdefghi"];
+
+    fn hill() -> (Vec<u8>, usize) {
+        let cells = HILL
+            .concat()
+            .bytes()
+            .map(|b| match b {
+                b'S' => b'a',
+                b'E' => b'z',
+                b => b,
+            })
+            .collect();
+        (cells, HILL[0].len())
+    }
+
+    #[test]
+    fn test_grid_bfs() {
+        // given a hill that can be climbed at most one step up at a time
+        let (cells, width) = hill();
+
+        // when searching it from the start
+        let paths = grid_bfs(&cells, width, [(0, 0)], |from, to| *to <= from + 1);
+
+        // expect the top to be 31 steps away, along a path of 32 positions
+        assert_eq!(Some(31), paths.cost(&(5, 2)));
+        let path = paths.path(&(5, 2)).unwrap();
+        assert_eq!(32, path.len());
+        assert_eq!((Some(&(0, 0)), Some(&(5, 2))), (path.first(), path.last()));
+    }
+
+    #[test]
+    fn test_bfs_several_starts() {
+        // given every lowest position of the hill as a start
+        let (cells, width) = hill();
+        let starts = (0..cells.len())
+            .filter(|&i| cells[i] == b'a')
+            .map(|i| (i % width, i / width));
+
+        // expect the closest one to be 29 steps from the top
+        let paths = grid_bfs(&cells, width, starts, |from, to| *to <= from + 1);
+        assert_eq!(Some(29), paths.cost(&(5, 2)));
+    }
+
+    #[test]
+    fn test_grid_bfs_outside_starts() {
+        // given starts outside the hill, on both sides and past a partial last row
+        let (mut cells, width) = hill();
+        cells.push(b'a');
+        let starts = [(8, 0), (0, 5), (usize::MAX, usize::MAX), (0, 0)];
+
+        // expect only the start on the hill to be searched from
+        let paths = grid_bfs(&cells, width, starts, |from, to| *to <= from + 1);
+        assert_eq!(Some(31), paths.cost(&(5, 2)));
+        assert_eq!(None, paths.cost(&(8, 0)));
+
+        // and a grid without width to have no paths
+        let paths = grid_bfs(&cells, 0, starts, |_, _| true);
+        assert_eq!(None, paths.cost(&(0, 0)));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // given a graph where the direct edge is more expensive than a detour
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+
+        // expect the detour to be taken
+        let paths = dijkstra(['a'], edges);
+        assert_eq!(Some(6), paths.cost(&'d'));
+        assert_eq!(Some(vec!['a', 'b', 'c', 'd']), paths.path(&'d'));
+        assert_eq!(None, paths.cost(&'e'));
+        assert_eq!(4, paths.reached().count());
+    }
+
+    #[test]
+    fn test_astar() {
+        // given an open 10 by 10 grid with a wall down the middle that has a gap at the bottom
+        let open = |&(x, y): &Position| x != 5 || y == 9;
+        let neighbours = |&position: &Position| {
+            grid_neighbours(position, 10, 10)
+                .filter(open)
+                .map(|neighbour| (neighbour, 1))
+                .collect::<Vec<_>>()
+        };
+        let distance = |&(x, y): &Position| x.abs_diff(9) + y.abs_diff(0);
+
+        // expect A* to go around the wall, as far as BFS does
+        let (path, cost) = astar((0, 0), neighbours, distance, |&node| node == (9, 0)).unwrap();
+        let steps = bfs([(0, 0)], |node| {
+            neighbours(node).into_iter().map(|(n, _)| n)
+        });
+        assert_eq!(Some(cost), steps.cost(&(9, 0)));
+        assert_eq!(cost + 1, path.len());
+        assert!(path.contains(&(5, 9)));
+
+        // and no path to be found to a goal behind a wall
+        let closed = |&(x, _): &Position| x != 5;
+        let blocked = |&position: &Position| {
+            grid_neighbours(position, 10, 10)
+                .filter(closed)
+                .map(|neighbour| (neighbour, 1))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            None,
+            astar((0, 0), blocked, distance, |&node| node == (9, 0))
+        );
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            grid_neighbours((0, 0), 3, 3).collect::<Vec<_>>()
+        );
+        assert_eq!(4, grid_neighbours((1, 1), 3, 3).count());
+    }
+}
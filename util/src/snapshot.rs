@@ -0,0 +1,123 @@
+//! Snapshot assertions, comparing multi-line output with the expected output stored in a file
+use std::fs;
+use std::path::Path;
+
+/// Setting this environment variable to `1` makes snapshot assertions write the actual output
+/// instead of comparing it
+pub const UPDATE_VARIABLE: &str = "UPDATE_SNAPSHOTS";
+
+/// Assert that some output equals the snapshot stored in the `snapshots` directory of the crate.
+///
+/// ```no_run
+/// util::assert_snapshot!("picture", "#.\n.#\n");
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr $(,)?) => {
+        $crate::snapshot::assert_snapshot(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("snapshots")
+                .join(format!("{}.txt", $name)),
+            $actual,
+        )
+    };
+}
+
+/// Assert that some output equals the snapshot stored at a path, panicking with a line diff if
+/// it doesn't, or update the snapshot if [`UPDATE_VARIABLE`] is set
+#[track_caller]
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: impl AsRef<str>) {
+    let (path, actual) = (path.as_ref(), actual.as_ref());
+    if std::env::var(UPDATE_VARIABLE).as_deref() == Ok("1") {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).expect("Could not create the snapshot directory");
+        }
+        fs::write(path, actual).expect("Could not write the snapshot");
+        return;
+    }
+    let expected = fs::read_to_string(path).unwrap_or_else(|error| {
+        panic!(
+            "Could not read snapshot {}: {}\nRun with {}=1 to create it",
+            path.display(),
+            error,
+            UPDATE_VARIABLE
+        )
+    });
+    if expected != actual {
+        panic!(
+            "Snapshot {} does not match, - expected, + actual:\n{}\nRun with {}=1 to update it",
+            path.display(),
+            diff_lines(&expected, actual),
+            UPDATE_VARIABLE
+        );
+    }
+}
+
+/// Diff two texts line by line, prefixing removed lines with `-`, added lines with `+` and
+/// unchanged lines with a space
+pub fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected = expected.split('\n').collect::<Vec<_>>();
+    let actual = actual.split('\n').collect::<Vec<_>>();
+
+    // Length of the longest common subsequence of the remaining lines, from every position
+    let mut common = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || (i < expected.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            diff.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    diff.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{assert_snapshot, diff_lines};
+    use std::fs;
+
+    #[test]
+    fn test_diff_lines() {
+        // given two texts where a line changed and a line was added
+        let expected = "#..#\n.##.\n#..#";
+        let actual = "#..#\n.#..\n#..#\n....";
+
+        // expect only the differing lines to be marked
+        assert_eq!(
+            "  #..#\n- .##.\n+ .#..\n  #..#\n+ ....",
+            diff_lines(expected, actual)
+        );
+    }
+
+    #[test]
+    fn test_assert_snapshot() {
+        // given a stored snapshot
+        let path = std::env::temp_dir().join(format!("util-snapshot-{}.txt", std::process::id()));
+        fs::write(&path, "#.\n.#\n").unwrap();
+
+        // expect matching output to pass and differing output to fail
+        assert_snapshot(&path, "#.\n.#\n");
+        let result = std::panic::catch_unwind(|| assert_snapshot(&path, "#.\n##\n"));
+        fs::remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+}
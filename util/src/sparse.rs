@@ -0,0 +1,323 @@
+//! Storage for grids that are mostly empty, over coordinates that may be far apart.
+//!
+//! A [`SparseGrid`] only stores the occupied points, like the positions a rope's tail visited,
+//! while keeping track of the area they cover. When only the order of coordinates matters,
+//! [`compress`] maps them to dense indexes that can be used in a flat vector.
+use std::collections::hash_map::{self, HashMap};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Sub};
+
+/// A point in a grid, where y grows downwards like in the drawings of the puzzles
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// Get the Manhattan distance to another point
+    pub fn manhattan(&self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// The smallest rectangle holding some points, including its edges
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    /// The top left corner
+    pub min: Point,
+    /// The bottom right corner
+    pub max: Point,
+}
+
+impl Bounds {
+    /// Get the bounds of a single point
+    pub fn new(point: Point) -> Bounds {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Grow the bounds to hold a point
+    pub fn include(&mut self, point: Point) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Get the number of columns, if it fits in a u64
+    pub fn width(&self) -> Option<u64> {
+        self.max.x.abs_diff(self.min.x).checked_add(1)
+    }
+
+    /// Get the number of rows, if it fits in a u64
+    pub fn height(&self) -> Option<u64> {
+        self.max.y.abs_diff(self.min.y).checked_add(1)
+    }
+
+    /// Check if a point lies on the edge of the bounds
+    fn on_edge(&self, point: Point) -> bool {
+        point.x == self.min.x
+            || point.x == self.max.x
+            || point.y == self.min.y
+            || point.y == self.max.y
+    }
+}
+
+/// A grid storing only the points that hold a value
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Put a value at a point, returning the value that was there
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(point),
+            None => self.bounds = Some(Bounds::new(point)),
+        }
+        self.cells.insert(point, value)
+    }
+
+    /// Take the value at a point, shrinking the bounds if it was on their edge
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(point)) {
+            self.bounds = self.cells.keys().fold(None, |bounds, &point| match bounds {
+                Some(mut bounds) => {
+                    bounds.include(point);
+                    Some(bounds)
+                }
+                None => Some(Bounds::new(point)),
+            });
+        }
+        Some(value)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Get the number of points holding a value
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Get the smallest rectangle holding every point with a value, which shrinks again when
+    /// points are removed, or none if the grid is empty
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Iterate over the points holding a value, in no particular order
+    pub fn iter(&self) -> hash_map::Iter<'_, Point, T> {
+        self.cells.iter()
+    }
+
+    /// Draw the area within the bounds row by row, with a character for every point
+    pub fn render(&self, mut draw: impl FnMut(Option<&T>) -> char) -> String {
+        let Some(Bounds { min, max }) = self.bounds else {
+            return String::new();
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| draw(self.get(Point::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (point, value) in cells {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+/// Coordinates mapped to the indexes of their order, without gaps between them
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compression<T> {
+    /// The unique coordinates, sorted
+    values: Vec<T>,
+}
+
+/// Map coordinates to dense indexes, where the lowest coordinate gets 0 and equal coordinates
+/// get the same index
+pub fn compress<T: Ord>(coordinates: impl IntoIterator<Item = T>) -> Compression<T> {
+    let mut values = coordinates.into_iter().collect::<Vec<_>>();
+    values.sort_unstable();
+    values.dedup();
+    Compression { values }
+}
+
+impl<T: Ord> Compression<T> {
+    /// Get the index of a coordinate, if it was compressed
+    pub fn index(&self, coordinate: &T) -> Option<usize> {
+        self.values.binary_search(coordinate).ok()
+    }
+
+    /// Get the coordinate of an index
+    pub fn coordinate(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+
+    /// Get the unique coordinates, in order
+    pub fn coordinates(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Get the number of unique coordinates
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{compress, Bounds, Point, SparseGrid};
+
+    #[test]
+    fn test_bounds_at_limits() {
+        // given bounds spanning all x and all but one y
+        let mut bounds = Bounds::new(Point::new(i64::MIN, i64::MIN));
+        bounds.include(Point::new(i64::MAX, i64::MAX - 1));
+
+        // expect only the size that fits to be given
+        assert_eq!(None, bounds.width());
+        assert_eq!(Some(u64::MAX), bounds.height());
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        // given a grid with points far apart
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::new(-1_000_000, 5), 'a');
+        grid.insert(Point::new(1_000_000, -5), 'b');
+        grid.insert(Point::new(0, 0), 'c');
+
+        // expect the bounds to hold all of them
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(Point::new(-1_000_000, -5), bounds.min);
+        assert_eq!(Point::new(1_000_000, 5), bounds.max);
+        assert_eq!(
+            (Some(2_000_001), Some(11)),
+            (bounds.width(), bounds.height())
+        );
+        assert_eq!(Some(&'c'), grid.get(Point::new(0, 0)));
+        assert_eq!(3, grid.len());
+
+        // and to shrink when points on the edge are removed
+        assert_eq!(Some('a'), grid.remove(Point::new(-1_000_000, 5)));
+        assert_eq!(None, grid.remove(Point::new(-1_000_000, 5)));
+        assert_eq!(
+            Some(Bounds {
+                min: Point::new(0, -5),
+                max: Point::new(1_000_000, 0)
+            }),
+            grid.bounds()
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        // given the positions visited by a rope's tail
+        let grid = [(0, 0), (1, 0), (2, 0), (2, -1), (0, -2)]
+            .into_iter()
+            .map(|(x, y)| (Point::new(x, y), ()))
+            .collect::<SparseGrid<_>>();
+
+        // expect the occupied area to be drawn
+        let drawing = grid.render(|cell| if cell.is_some() { '#' } else { '.' });
+        assert_eq!("#..\n..#\n###", drawing);
+        assert_eq!("", SparseGrid::<()>::new().render(|_| '#'));
+    }
+
+    #[test]
+    fn test_compress() {
+        // given coordinates with big gaps and duplicates
+        let compression = compress([1_000_000, -7, 42, 42, 1_000_000]);
+
+        // expect them to be numbered in order
+        assert_eq!(3, compression.len());
+        assert_eq!(Some(0), compression.index(&-7));
+        assert_eq!(Some(2), compression.index(&1_000_000));
+        assert_eq!(None, compression.index(&0));
+        assert_eq!(Some(&42), compression.coordinate(1));
+        assert_eq!(&[-7, 42, 1_000_000], compression.coordinates());
+    }
+
+    #[test]
+    fn test_point() {
+        let point = Point::new(1, -2) + Point::new(3, 4) - Point::new(1, 1);
+        assert_eq!(Point::new(3, 1), point);
+        assert_eq!(5, Point::new(0, 0).manhattan(Point::new(-2, 3)));
+        assert_eq!("3,1", point.to_string());
+    }
+}
@@ -0,0 +1,37 @@
+//! Tracing of what the solutions are doing, silent unless a runner installs a subscriber.
+//!
+//! Days emit events for their steps with the re-exported macros:
+//!
+//! ```
+//! util::trace::debug!(count = 3, from = 1, to = 2, "Moving crates");
+//! ```
+pub use tracing::{debug, error, info, trace, warn, Level, Span};
+
+/// A span around loading and parsing the input of a day
+pub fn parse_span(year: u16, day: u8) -> Span {
+    tracing::info_span!("parse", year, day)
+}
+
+/// A span around running a part of a day
+pub fn part_span(year: u16, day: u8, part: u8) -> Span {
+    tracing::info_span!("part", year, day, part)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{debug, part_span};
+
+    #[test]
+    fn test_silent_without_subscriber() {
+        // given no subscriber, expect spans to be disabled and events to skip their fields
+        let mut evaluated = false;
+        debug!(
+            count = {
+                evaluated = true;
+                3
+            }
+        );
+        assert!(!evaluated);
+        assert!(part_span(2022, 1, 1).is_disabled());
+    }
+}